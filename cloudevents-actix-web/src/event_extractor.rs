@@ -0,0 +1,136 @@
+use crate::EventReader;
+use actix_web::dev::Payload;
+use actix_web::error::{ErrorBadRequest, ErrorPayloadTooLarge};
+use actix_web::web::BytesMut;
+use actix_web::{Error, FromRequest, HttpRequest};
+use cloudevents::http::{self, EventReader as _};
+use cloudevents::Event;
+use futures::future::{FutureExt, LocalBoxFuture};
+use futures::StreamExt;
+use std::ops;
+
+const DEFAULT_LIMIT: usize = 262_144;
+
+/// Extracts a single event from the request, either in binary or structured mode.
+///
+/// Requests without an event, with a batch or failing to be read are rejected with 400 Bad Request.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CloudEvent(pub Event);
+
+/// Extracts the [`http::HttpEvent`] from the request, preserving the mode and the [`http::EventFormat`]
+/// it was encoded with, so that replying with it writes the event back the same way.
+///
+/// Requests without an event or failing to be read are rejected with 400 Bad Request.
+#[derive(PartialEq, Debug, Clone)]
+pub struct HttpCloudEvent(pub http::HttpEvent, pub http::EventFormat);
+
+/// Configuration of the [`CloudEvent`] and [`HttpCloudEvent`] extractors,
+/// to be registered with `app_data`
+#[derive(Clone)]
+pub struct EventConfig {
+    limit: usize,
+    strict: bool,
+}
+
+impl EventConfig {
+    /// Sets the maximum size in bytes of the request body, 256 KiB (262_144 bytes) by default.
+    /// Larger bodies are rejected with 413 Payload Too Large.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Rejects with 400 Bad Request the events not conforming to the spec,
+    /// as reported by [`http::EventReader::read_cloud_event_strict`]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+impl Default for EventConfig {
+    fn default() -> Self {
+        EventConfig {
+            limit: DEFAULT_LIMIT,
+            strict: false,
+        }
+    }
+}
+
+impl CloudEvent {
+    pub fn into_inner(self) -> Event {
+        self.0
+    }
+}
+
+impl ops::Deref for CloudEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Event {
+        &self.0
+    }
+}
+
+impl HttpCloudEvent {
+    pub fn into_inner(self) -> http::HttpEvent {
+        self.0
+    }
+}
+
+impl FromRequest for CloudEvent {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Error>>;
+    type Config = EventConfig;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        extract(req, payload)
+            .map(|res| match res?.0 {
+                http::HttpEvent::Binary(e) | http::HttpEvent::Structured(e) => Ok(CloudEvent(e)),
+                http::HttpEvent::Batch(_) => Err(ErrorBadRequest("Expecting a single event, found a batch")),
+            })
+            .boxed_local()
+    }
+}
+
+impl FromRequest for HttpCloudEvent {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Error>>;
+    type Config = EventConfig;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        extract(req, payload)
+            .map(|res| res.map(|(event, format)| HttpCloudEvent(event, format)))
+            .boxed_local()
+    }
+}
+
+fn extract(
+    req: &HttpRequest,
+    payload: &mut Payload,
+) -> LocalBoxFuture<'static, Result<(http::HttpEvent, http::EventFormat), Error>> {
+    let cfg = req.app_data::<EventConfig>().cloned().unwrap_or_default();
+    let req = req.clone();
+    let mut payload = payload.take();
+
+    async move {
+        let mut body = BytesMut::new();
+        while let Some(chunk) = payload.next().await {
+            let chunk = chunk?;
+            if body.len() + chunk.len() > cfg.limit {
+                return Err(ErrorPayloadTooLarge(format!("Body is larger than {} bytes", cfg.limit)));
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        let r = (req, body.freeze());
+        let event = if cfg.strict {
+            EventReader::read_cloud_event_with_format_strict(r)
+        } else {
+            EventReader::read_cloud_event_with_format(r)
+        };
+        event
+            .map_err(ErrorBadRequest)?
+            .ok_or_else(|| ErrorBadRequest("No CloudEvent found in the request"))
+    }
+    .boxed_local()
+}
@@ -0,0 +1,27 @@
+use crate::{CloudEvent, EventWriter, HttpCloudEvent};
+use actix_web::error::ErrorInternalServerError;
+use actix_web::{Error, HttpRequest, HttpResponse, Responder};
+use cloudevents::http::{self, EventWriter as _};
+use futures::future::{ready, Ready};
+
+/// Replies with the event in binary mode.
+///
+/// Use `Responder::with_status` to reply with a status code other than 200, like 202 Accepted
+impl Responder for CloudEvent {
+    type Error = Error;
+    type Future = Ready<Result<HttpResponse, Error>>;
+
+    fn respond_to(self, req: &HttpRequest) -> Self::Future {
+        HttpCloudEvent(http::HttpEvent::Binary(self.0), http::EventFormat::Json).respond_to(req)
+    }
+}
+
+/// Replies with the event in the mode of the wrapped `HttpEvent` and in the wrapped `EventFormat`
+impl Responder for HttpCloudEvent {
+    type Error = Error;
+    type Future = Ready<Result<HttpResponse, Error>>;
+
+    fn respond_to(self, _: &HttpRequest) -> Self::Future {
+        ready(EventWriter::write_cloud_event_with_format(self.0, self.1).map_err(ErrorInternalServerError))
+    }
+}
@@ -0,0 +1,48 @@
+use crate::event_writer::encode;
+use crate::EventReader;
+use actix_web::client::Client;
+use actix_web::Error;
+use cloudevents::http::{self, EventReader as _};
+
+/// Sends events to a sink with the awc client
+pub struct EventSender {
+    client: Client,
+    sink: String,
+}
+
+impl EventSender {
+    pub fn new<S: Into<String>>(client: Client, sink: S) -> EventSender {
+        EventSender {
+            client,
+            sink: sink.into(),
+        }
+    }
+
+    /// POSTs the event to the sink, returning the event the sink replied with, if any.
+    ///
+    /// Replies with a non 2xx status code are reported as `SenderError::InvalidStatus`
+    pub async fn send(&self, event: http::HttpEvent) -> Result<Option<http::HttpEvent>, http::SenderError> {
+        let encoded = encode(event, http::EventFormat::Json)?;
+
+        let mut req = self.client.post(&self.sink);
+        for (name, value) in encoded.headers {
+            req = req.header(name, value);
+        }
+        let mut response = match encoded.content_type {
+            Some(ct) => req.content_type(ct).send_body(encoded.body).await,
+            None => req.send().await,
+        }
+        .map_err(|e| http::SenderError::Other(Box::new(Error::from(e))))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(http::SenderError::InvalidStatus {status: status.as_u16()});
+        }
+
+        let body = response
+            .body()
+            .await
+            .map_err(|e| http::SenderError::Other(Box::new(Error::from(e))))?;
+        Ok(EventReader::read_cloud_event((response.headers().clone(), body))?)
+    }
+}
@@ -0,0 +1,33 @@
+use actix_web::error::ErrorInternalServerError;
+use actix_web::http::header::CACHE_CONTROL;
+use actix_web::web::Bytes;
+use actix_web::{Error, HttpRequest, HttpResponse, Responder};
+use cloudevents::{sse, Event};
+use futures::future::{ready, Ready};
+use futures::{Stream, StreamExt};
+
+/// Streams the events as Server-Sent Events, see [`cloudevents::sse`].
+///
+/// Events failing to be written abort the stream.
+pub struct CloudEventStream<S>(pub S);
+
+impl<S> Responder for CloudEventStream<S>
+where
+    S: Stream<Item = Event> + 'static,
+{
+    type Error = Error;
+    type Future = Ready<Result<HttpResponse, Error>>;
+
+    fn respond_to(self, _: &HttpRequest) -> Self::Future {
+        let body = self.0.map(|event| {
+            sse::write_event(&event)
+                .map(Bytes::from)
+                .map_err(ErrorInternalServerError)
+        });
+
+        ready(Ok(HttpResponse::Ok()
+            .content_type(sse::SSE_CONTENT_TYPE)
+            .header(CACHE_CONTROL, "no-cache")
+            .streaming(body)))
+    }
+}
@@ -0,0 +1,62 @@
+use actix::{Actor, ActorContext, StreamHandler};
+use actix_web::error::ErrorBadRequest;
+use actix_web::http::header::SEC_WEBSOCKET_PROTOCOL;
+use actix_web::{web, Error, HttpRequest, HttpResponse};
+use actix_web_actors::ws;
+use cloudevents::http;
+use cloudevents::websocket::{self, Frame, Subprotocol};
+use cloudevents::Event;
+use futures::channel::mpsc;
+
+/// Stream of the events received on a WebSocket session started with [`start_event_stream`],
+/// ending when the session is closed
+pub type EventStream = mpsc::UnboundedReceiver<Result<Event, http::ReaderError>>;
+
+/// Performs the WebSocket handshake negotiating a CloudEvents subprotocol,
+/// and starts an actor decoding the events in the frames received from the client.
+///
+/// Returns the handshake response, to be returned by the handler, and the stream of received events.
+/// Requests not offering a supported subprotocol are rejected with 400 Bad Request.
+pub fn start_event_stream(req: &HttpRequest, payload: web::Payload) -> Result<(HttpResponse, EventStream), Error> {
+    let subprotocol = req
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|h| h.to_str().ok())
+        .and_then(Subprotocol::negotiate)
+        .ok_or_else(|| ErrorBadRequest("No supported CloudEvents subprotocol offered"))?;
+
+    let (events, stream) = mpsc::unbounded();
+    let session = EventSession { subprotocol, events };
+    let res = ws::start_with_protocols(session, &[subprotocol.name()], req, payload)?;
+    Ok((res, stream))
+}
+
+struct EventSession {
+    subprotocol: Subprotocol,
+    events: mpsc::UnboundedSender<Result<Event, http::ReaderError>>,
+}
+
+impl Actor for EventSession {
+    type Context = ws::WebsocketContext<Self>;
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for EventSession {
+    fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        let frame = match msg {
+            Ok(ws::Message::Text(text)) => Frame::Text(text),
+            Ok(ws::Message::Binary(data)) => Frame::Binary(data.to_vec()),
+            Ok(ws::Message::Ping(msg)) => return ctx.pong(&msg),
+            Ok(ws::Message::Close(reason)) => {
+                ctx.close(reason);
+                return ctx.stop();
+            }
+            Ok(_) => return,
+            Err(_) => return ctx.stop(),
+        };
+
+        // Close the session when the stream of events has been dropped
+        if self.events.unbounded_send(websocket::read_event(&frame, self.subprotocol)).is_err() {
+            ctx.stop();
+        }
+    }
+}
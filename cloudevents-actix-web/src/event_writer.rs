@@ -1,48 +1,54 @@
+use actix_web::http::HeaderName;
 use actix_web::HttpResponse;
 use cloudevents::http;
-use cloudevents::Event;
-use serde::Serialize;
+use std::convert::TryFrom;
 
 pub struct EventWriter {}
 
+// Headers and body of an encoded event, shared by server responses and client requests
+pub(crate) struct EncodedEvent {
+    pub(crate) headers: Vec<(HeaderName, String)>,
+    pub(crate) content_type: Option<String>,
+    pub(crate) body: Vec<u8>,
+}
+
 impl http::EventWriter<HttpResponse> for EventWriter {
     fn write_cloud_event(res: http::HttpEvent) -> Result<HttpResponse, http::WriterError> {
-        match res {
-            http::HttpEvent::Binary(e) => write_binary(e),
-            http::HttpEvent::Structured(e) => serialize_and_write(e, http::CE_JSON_CONTENT_TYPE),
-            http::HttpEvent::Batch(vec) => serialize_and_write(vec, http::CE_BATCH_JSON_CONTENT_TYPE),
-        }
+        Self::write_cloud_event_with_format(res, http::EventFormat::Json)
     }
-}
 
-fn write_binary(event: Event) -> Result<HttpResponse, http::WriterError> {
-    // Write headers
-    let mut builder = HttpResponse::Ok();
-    builder.header(http::CE_ID_HEADER, event.id);
-    builder.header(http::CE_SPECVERSION_HEADER, event.spec_version.to_string());
-    builder.header(http::CE_SOURCE_HEADER, event.source);
-    builder.header(http::CE_TYPE_HEADER, event.event_type);
-    if let Some(sub) = event.subject {
-        builder.header(http::CE_SUBJECT_HEADER, sub);
-    }
-    if let Some(time) = event.time {
-        builder.header(http::CE_TIME_HEADER, time.to_rfc3339());
-    }
-    let result = if let Some(p) = event.payload {
-        builder.content_type(p.content_type).body(p.data)
-    } else {
-        builder.finish()
-    };
+    fn write_cloud_event_with_format(res: http::HttpEvent, format: http::EventFormat) -> Result<HttpResponse, http::WriterError> {
+        let encoded = encode(res, format)?;
+
+        let mut builder = HttpResponse::Ok();
+        for (name, value) in encoded.headers {
+            builder.header(name, value);
+        }
+        let result = if let Some(ct) = encoded.content_type {
+            builder.content_type(ct).body(encoded.body)
+        } else {
+            builder.finish()
+        };
 
-    Ok(result)
+        Ok(result)
+    }
 }
 
-fn serialize_and_write<T: Serialize>(value: T, content_type: &str) -> Result<HttpResponse, http::WriterError> {
-    Ok(serde_json::to_vec(&value)
-        .map(|j| {
-            HttpResponse::Ok()
-                .content_type(content_type)
-                .body(j)
-        })?
-    )
+pub(crate) fn encode(res: http::HttpEvent, format: http::EventFormat) -> Result<EncodedEvent, http::WriterError> {
+    let encoded = http::write_event_with_format(res, format)?;
+    let headers = encoded
+        .headers
+        .into_iter()
+        .map(|(name, value)| {
+            HeaderName::try_from(name.as_str())
+                .map(|name| (name, value))
+                .map_err(|e| http::WriterError::Other(Box::new(e)))
+        })
+        .collect::<Result<Vec<(HeaderName, String)>, http::WriterError>>()?;
+
+    Ok(EncodedEvent {
+        headers,
+        content_type: encoded.content_type,
+        body: encoded.body,
+    })
 }
@@ -1,5 +1,14 @@
+mod event_extractor;
 mod event_reader;
+mod event_responder;
+mod event_sender;
+mod event_stream_responder;
+mod event_websocket;
 mod event_writer;
 
+pub use event_extractor::{CloudEvent, EventConfig, HttpCloudEvent};
 pub use event_reader::EventReader;
+pub use event_sender::EventSender;
+pub use event_stream_responder::CloudEventStream;
+pub use event_websocket::{start_event_stream, EventStream};
 pub use event_writer::EventWriter;
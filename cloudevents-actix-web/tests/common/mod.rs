@@ -0,0 +1,18 @@
+//! Events shared by the integration tests
+#![allow(dead_code)]
+
+use cloudevents::{Event, EventBuilder, Payload};
+
+/// Event with the required attributes and a `text/plain` payload
+pub fn event() -> Event {
+    EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello".as_bytes().into(),
+        })
+        .build()
+        .unwrap()
+}
@@ -0,0 +1,34 @@
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use cloudevents::sse::SseParser;
+use cloudevents::{Event, EventBuilder};
+use cloudevents_actix_web::CloudEventStream;
+use futures::stream;
+
+fn event(id: &str) -> Event {
+    EventBuilder::default()
+        .id(id)
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .build()
+        .unwrap()
+}
+
+async fn events() -> CloudEventStream<stream::Iter<std::vec::IntoIter<Event>>> {
+    CloudEventStream(stream::iter(vec![event("1"), event("2")]))
+}
+
+#[actix_rt::test]
+async fn test_stream_events() {
+    let mut app = test::init_service(App::new().route("/", web::get().to(events))).await;
+
+    let resp = test::call_service(&mut app, test::TestRequest::get().uri("/").to_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get("content-type").unwrap(), "text/event-stream");
+    let body = test::read_body(resp).await;
+    assert!(body.starts_with(b"id: 1\nevent: com.github.pull.create\ndata: "));
+
+    let parsed: Vec<Event> = SseParser::new().feed(&body).into_iter().map(Result::unwrap).collect();
+    assert_eq!(parsed, vec![event("1"), event("2")]);
+}
@@ -0,0 +1,127 @@
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse};
+use cloudevents::http::HttpEvent;
+use cloudevents_actix_web::{CloudEvent, EventConfig, HttpCloudEvent};
+
+async fn echo_id(event: CloudEvent) -> HttpResponse {
+    HttpResponse::Ok().body(event.id.clone())
+}
+
+async fn echo_mode(event: HttpCloudEvent) -> HttpResponse {
+    let mode = match event.into_inner() {
+        HttpEvent::Binary(_) => "binary",
+        HttpEvent::Structured(_) => "structured",
+        HttpEvent::Batch(_) => "batch",
+    };
+    HttpResponse::Ok().body(mode)
+}
+
+fn binary_request() -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "1.0")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("content-type", "application/json")
+        .set_payload(r#"{"hello":"world"}"#)
+}
+
+#[actix_rt::test]
+async fn test_extract_binary_event() {
+    let mut app = test::init_service(App::new().route("/", web::post().to(echo_id))).await;
+
+    let resp = test::call_service(&mut app, binary_request().to_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "A234-1234-1234");
+}
+
+#[actix_rt::test]
+async fn test_extract_batch_http_event() {
+    let mut app = test::init_service(App::new().route("/", web::post().to(echo_mode))).await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/cloudevents-batch+json")
+        .set_payload(
+            r#"[{"id":"1","specversion":"1.0","type":"t","source":"/s"}]"#,
+        )
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "batch");
+}
+
+#[actix_rt::test]
+async fn test_reject_batch_for_single_event() {
+    let mut app = test::init_service(App::new().route("/", web::post().to(echo_id))).await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", "application/cloudevents-batch+json")
+        .set_payload("[]")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_rt::test]
+async fn test_reject_invalid_event_with_reader_error() {
+    let mut app = test::init_service(App::new().route("/", web::post().to(echo_id))).await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "9.9")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        test::read_body(resp).await,
+        "Invalid metadata 'specversion': Invalid specversion 9.9"
+    );
+}
+
+#[actix_rt::test]
+async fn test_reject_request_without_event() {
+    let mut app = test::init_service(App::new().route("/", web::post().to(echo_id))).await;
+
+    let resp = test::call_service(&mut app, test::TestRequest::post().uri("/").to_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_rt::test]
+async fn test_reject_body_over_limit() {
+    let mut app = test::init_service(
+        App::new()
+            .app_data(EventConfig::default().limit(8))
+            .route("/", web::post().to(echo_id)),
+    )
+    .await;
+
+    let resp = test::call_service(&mut app, binary_request().to_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[actix_rt::test]
+async fn test_strict_rejects_invalid_event() {
+    let mut app = test::init_service(
+        App::new()
+            .app_data(EventConfig::default().strict(true))
+            .route("/", web::post().to(echo_id)),
+    )
+    .await;
+
+    let req = binary_request().header("ce-Invalid_Name", "value").to_request();
+    let resp = test::call_service(&mut app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,91 @@
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, Responder};
+use cloudevents::http::{EventFormat, HttpEvent};
+use cloudevents::{Event, EventBuilder};
+use cloudevents_actix_web::{CloudEvent, HttpCloudEvent};
+
+fn reply_event() -> Event {
+    EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.reply")
+        .build()
+        .unwrap()
+}
+
+#[actix_rt::test]
+async fn test_respond_binary() {
+    let mut app = test::init_service(
+        App::new().route("/", web::post().to(|| async { CloudEvent(reply_event()) })),
+    )
+    .await;
+
+    let resp = test::call_service(&mut app, test::TestRequest::post().uri("/").to_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers().get("ce-id").unwrap(), "A234-1234-1234");
+    assert_eq!(resp.headers().get("ce-type").unwrap(), "com.github.pull.reply");
+}
+
+#[actix_rt::test]
+async fn test_respond_structured_with_status() {
+    let mut app = test::init_service(App::new().route(
+        "/",
+        web::post().to(|| async {
+            HttpCloudEvent(HttpEvent::Structured(reply_event()), EventFormat::Json).with_status(StatusCode::ACCEPTED)
+        }),
+    ))
+    .await;
+
+    let resp = test::call_service(&mut app, test::TestRequest::post().uri("/").to_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::ACCEPTED);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        cloudevents::http::CE_JSON_CONTENT_TYPE
+    );
+    let body: Event = serde_json::from_slice(&test::read_body(resp).await).unwrap();
+    assert_eq!(body, reply_event());
+}
+
+#[actix_rt::test]
+async fn test_echo_event() {
+    let mut app = test::init_service(
+        App::new().route("/", web::post().to(|event: CloudEvent| async { event.with_status(StatusCode::CREATED) })),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "1.0")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("ce-traceparent", "abc")
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(resp.headers().get("ce-id").unwrap(), "A234-1234-1234");
+    assert_eq!(resp.headers().get("ce-traceparent").unwrap(), "abc");
+}
+
+#[actix_rt::test]
+async fn test_echo_protobuf_http_event() {
+    let mut app = test::init_service(App::new().route("/", web::post().to(|event: HttpCloudEvent| async { event }))).await;
+
+    let req = test::TestRequest::post()
+        .uri("/")
+        .header("content-type", cloudevents::http::CE_PROTOBUF_CONTENT_TYPE)
+        .set_payload(cloudevents::protobuf::to_vec(reply_event()))
+        .to_request();
+    let resp = test::call_service(&mut app, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get("content-type").unwrap(),
+        cloudevents::http::CE_PROTOBUF_CONTENT_TYPE
+    );
+    let body = test::read_body(resp).await;
+    assert_eq!(cloudevents::protobuf::from_slice(&body).unwrap(), reply_event());
+}
@@ -0,0 +1,161 @@
+use actix_web::body::{Body, ResponseBody};
+use actix_web::test::TestRequest;
+use actix_web::web::Bytes;
+use actix_web::HttpResponse;
+use chrono::DateTime;
+use cloudevents::http::{EventFormat, EventReader as _, EventWriter as _, HttpEvent};
+use cloudevents::{Event, EventBuilder, ExtensionValue, Payload, SpecVersion};
+use cloudevents_actix_web::{EventReader, EventWriter};
+use url::Url;
+
+fn full_event(spec_version: SpecVersion) -> Event {
+    let mut event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .spec_version(spec_version)
+        .event_type("com.github.pull.create")
+        .subject("123")
+        .time(DateTime::parse_from_rfc3339("2018-04-05T17:31:00Z").unwrap())
+        .data_schema(Url::parse("https://example.com/schemas/pull.json").unwrap())
+        .payload(Payload {
+            content_type: "application/json".to_string(),
+            data: r#"{"hello":"world"}"#.as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+    event.extensions.insert(
+        "traceparent".to_string(),
+        ExtensionValue::from("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"),
+    );
+    event.extensions.insert("partitionkey".to_string(), ExtensionValue::from("key-1"));
+    event
+}
+
+fn response_to_request(response: HttpResponse) -> (actix_web::HttpRequest, Bytes) {
+    let body = match response.body() {
+        ResponseBody::Body(Body::Bytes(b)) => b.clone(),
+        _ => Bytes::new(),
+    };
+    let mut req = TestRequest::default();
+    for (name, value) in response.headers() {
+        req = req.header(name.clone(), value.clone());
+    }
+    (req.set_payload(body.clone()).to_http_request(), body)
+}
+
+fn round_trip(event: HttpEvent) -> HttpEvent {
+    round_trip_with_format(event, EventFormat::Json)
+}
+
+fn round_trip_with_format(event: HttpEvent, format: EventFormat) -> HttpEvent {
+    let response = EventWriter::write_cloud_event_with_format(event, format).unwrap();
+    let first = EventReader::read_cloud_event(response_to_request(response))
+        .unwrap()
+        .unwrap();
+
+    // Write again what we read, to prove the reader output can be written back
+    let response = EventWriter::write_cloud_event_with_format(first.clone(), format).unwrap();
+    let second = EventReader::read_cloud_event(response_to_request(response))
+        .unwrap()
+        .unwrap();
+    assert_eq!(first, second);
+    second
+}
+
+#[test]
+fn test_binary_round_trip() {
+    for spec_version in [SpecVersion::V03, SpecVersion::V10] {
+        let event = full_event(spec_version);
+
+        assert_eq!(round_trip(HttpEvent::Binary(event.clone())), HttpEvent::Binary(event));
+    }
+}
+
+#[test]
+fn test_binary_round_trip_no_payload() {
+    let mut event = full_event(SpecVersion::V10);
+    event.payload = None;
+
+    assert_eq!(round_trip(HttpEvent::Binary(event.clone())), HttpEvent::Binary(event));
+}
+
+#[test]
+fn test_binary_writes_extensions_as_headers() {
+    let response = EventWriter::write_cloud_event(HttpEvent::Binary(full_event(SpecVersion::V10))).unwrap();
+
+    assert_eq!(
+        response.headers().get("ce-traceparent").unwrap(),
+        "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+    );
+    assert_eq!(response.headers().get("ce-partitionkey").unwrap(), "key-1");
+}
+
+#[test]
+fn test_structured_round_trip() {
+    let event = full_event(SpecVersion::V10);
+
+    assert_eq!(
+        round_trip(HttpEvent::Structured(event.clone())),
+        HttpEvent::Structured(event)
+    );
+}
+
+#[test]
+fn test_batch_round_trip() {
+    let events = vec![full_event(SpecVersion::V03), full_event(SpecVersion::V10)];
+
+    assert_eq!(round_trip(HttpEvent::Batch(events.clone())), HttpEvent::Batch(events));
+}
+
+#[test]
+fn test_structured_protobuf_round_trip() {
+    let event = full_event(SpecVersion::V10);
+
+    assert_eq!(
+        round_trip_with_format(HttpEvent::Structured(event.clone()), EventFormat::Protobuf),
+        HttpEvent::Structured(event)
+    );
+}
+
+#[test]
+fn test_batch_protobuf_round_trip() {
+    let events = vec![full_event(SpecVersion::V03), full_event(SpecVersion::V10)];
+
+    assert_eq!(
+        round_trip_with_format(HttpEvent::Batch(events.clone()), EventFormat::Protobuf),
+        HttpEvent::Batch(events)
+    );
+}
+
+#[test]
+fn test_binary_and_structured_extensions_have_same_names() {
+    let event = full_event(SpecVersion::V10);
+    let binary = response_to_request(EventWriter::write_cloud_event(HttpEvent::Binary(event.clone())).unwrap());
+    let structured = response_to_request(EventWriter::write_cloud_event(HttpEvent::Structured(event)).unwrap());
+
+    match (
+        EventReader::read_cloud_event(binary).unwrap().unwrap(),
+        EventReader::read_cloud_event(structured).unwrap().unwrap(),
+    ) {
+        (HttpEvent::Binary(b), HttpEvent::Structured(s)) => {
+            assert!(b.extensions.contains_key("traceparent"));
+            assert_eq!(b.extensions, s.extensions);
+        }
+        _ => panic!("Expecting a binary and a structured event"),
+    }
+}
+
+#[test]
+fn test_binary_round_trip_percent_encoded_values() {
+    let mut event = full_event(SpecVersion::V10);
+    event.subject = Some("Ünïcödé subject with 100% \"quotes\"".to_string());
+    event.extensions.insert("comment".to_string(), ExtensionValue::from("😀 hello"));
+
+    let response = EventWriter::write_cloud_event(HttpEvent::Binary(event.clone())).unwrap();
+    assert_eq!(
+        response.headers().get("ce-subject").unwrap(),
+        "%C3%9Cn%C3%AFc%C3%B6d%C3%A9%20subject%20with%20100%25%20%22quotes%22"
+    );
+
+    assert_eq!(round_trip(HttpEvent::Binary(event.clone())), HttpEvent::Binary(event));
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use actix_web::client::Client;
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, HttpResponse, Responder};
+use cloudevents::http::{HttpEvent, SenderError};
+use cloudevents_actix_web::{CloudEvent, EventSender, HttpCloudEvent};
+use common::event;
+
+async fn accept(_: CloudEvent) -> HttpResponse {
+    HttpResponse::Accepted().finish()
+}
+
+#[actix_rt::test]
+async fn test_send_and_read_reply() {
+    let srv = test::start(|| {
+        App::new().route("/", web::post().to(|event: HttpCloudEvent| async { event }))
+    });
+
+    let reply = EventSender::new(Client::default(), srv.url("/"))
+        .send(HttpEvent::Structured(event()))
+        .await
+        .unwrap();
+
+    assert_eq!(reply, Some(HttpEvent::Structured(event())));
+}
+
+#[actix_rt::test]
+async fn test_send_no_reply() {
+    let srv = test::start(|| {
+        App::new().route("/", web::post().to(accept))
+    });
+
+    let reply = EventSender::new(Client::default(), srv.url("/"))
+        .send(HttpEvent::Binary(event()))
+        .await
+        .unwrap();
+
+    assert_eq!(reply, None);
+}
+
+#[actix_rt::test]
+async fn test_send_invalid_status() {
+    let srv = test::start(|| {
+        App::new().route(
+            "/",
+            web::post().to(|event: CloudEvent| async { event.with_status(StatusCode::SERVICE_UNAVAILABLE) }),
+        )
+    });
+
+    let result = EventSender::new(Client::default(), srv.url("/"))
+        .send(HttpEvent::Binary(event()))
+        .await;
+
+    match result {
+        Err(SenderError::InvalidStatus { status }) => assert_eq!(status, 503),
+        _ => panic!("Expecting invalid status error"),
+    }
+}
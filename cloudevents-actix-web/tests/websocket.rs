@@ -0,0 +1,71 @@
+mod common;
+
+use actix_web::client::{Client, WsClientError};
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App, Error, HttpRequest, HttpResponse};
+use actix_web_actors::ws;
+use cloudevents::websocket::{write_event, Frame, Subprotocol};
+use cloudevents_actix_web::start_event_stream;
+use common::event;
+use futures::{SinkExt, StreamExt};
+use std::sync::mpsc;
+use std::time::Duration;
+
+type Received = mpsc::Sender<Result<String, String>>;
+
+// Forwards the ids of the received events, or the reader errors
+async fn receive(req: HttpRequest, payload: web::Payload, received: web::Data<Received>) -> Result<HttpResponse, Error> {
+    let (res, mut events) = start_event_stream(&req, payload)?;
+    let received = received.get_ref().clone();
+    actix_rt::spawn(async move {
+        while let Some(event) = events.next().await {
+            received.send(event.map(|e| e.id).map_err(|e| e.to_string())).unwrap();
+        }
+    });
+    Ok(res)
+}
+
+#[actix_rt::test]
+async fn test_receive_event_stream() {
+    let (tx, rx): (Received, _) = mpsc::channel();
+    let srv = test::start(move || App::new().data(tx.clone()).route("/", web::get().to(receive)));
+
+    let (res, mut framed) = Client::new()
+        .ws(srv.url("/"))
+        .protocols(["cloudevents.json"])
+        .connect()
+        .await
+        .unwrap();
+    assert_eq!(res.headers().get("sec-websocket-protocol").unwrap(), "cloudevents.json");
+
+    let text = match write_event(&event(), Subprotocol::Json).unwrap() {
+        Frame::Text(text) => text,
+        f => panic!("Expecting text frame, found {:?}", f),
+    };
+    framed.send(ws::Message::Text(text.clone())).await.unwrap();
+    framed.send(ws::Message::Binary(text.into_bytes().into())).await.unwrap();
+    framed.send(ws::Message::Text("{}".to_string())).await.unwrap();
+    framed.send(ws::Message::Close(None)).await.unwrap();
+
+    let timeout = Duration::from_secs(5);
+    assert_eq!(rx.recv_timeout(timeout).unwrap(), Ok("A234-1234-1234".to_string()));
+    assert_eq!(rx.recv_timeout(timeout).unwrap(), Ok("A234-1234-1234".to_string()));
+    assert!(rx.recv_timeout(timeout).unwrap().is_err());
+}
+
+#[actix_rt::test]
+async fn test_reject_unsupported_subprotocol() {
+    let (tx, _rx): (Received, _) = mpsc::channel();
+    let srv = test::start(move || App::new().data(tx.clone()).route("/", web::get().to(receive)));
+
+    let result = Client::new()
+        .ws(srv.url("/"))
+        .protocols(["cloudevents.avro"])
+        .connect()
+        .await;
+
+    match result {
+        Err(WsClientError::InvalidResponseStatus(status)) => assert_eq!(status, StatusCode::BAD_REQUEST),
+        _ => panic!("Expecting handshake to be rejected"),
+    }
+}
@@ -0,0 +1,107 @@
+use axum::body::Bytes;
+use axum::extract::rejection::BytesRejection;
+use axum::extract::{FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use cloudevents::http::{self, EventReader as _, HttpReader};
+use cloudevents::Event;
+use std::{fmt, ops};
+
+/// Extracts a single event from the request, either in binary or structured mode.
+///
+/// Requests without an event, with a batch or failing to be read are rejected with 400 Bad Request.
+/// The body size is limited by [`axum::extract::DefaultBodyLimit`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct CloudEvent(pub Event);
+
+/// Extracts the [`http::HttpEvent`] from the request, preserving the mode and the [`http::EventFormat`]
+/// it was encoded with, so that responding with it writes the event back the same way.
+///
+/// Requests without an event or failing to be read are rejected with 400 Bad Request.
+#[derive(PartialEq, Debug, Clone)]
+pub struct HttpCloudEvent(pub http::HttpEvent, pub http::EventFormat);
+
+/// Rejection of the [`CloudEvent`] and [`HttpCloudEvent`] extractors
+#[derive(Debug)]
+pub enum EventRejection {
+    /// The body could not be buffered, e.g. because it's over the limit
+    Body(BytesRejection),
+    /// The event could not be read, with the [`http::ReaderError`] text
+    InvalidEvent(String),
+    NoEvent,
+    UnexpectedBatch,
+}
+
+impl fmt::Display for EventRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventRejection::Body(e) => write!(f, "{}", e.body_text()),
+            EventRejection::InvalidEvent(reason) => write!(f, "{}", reason),
+            EventRejection::NoEvent => write!(f, "No CloudEvent found in the request"),
+            EventRejection::UnexpectedBatch => write!(f, "Expecting a single event, found a batch"),
+        }
+    }
+}
+
+impl From<http::ReaderError> for EventRejection {
+    fn from(e: http::ReaderError) -> Self {
+        EventRejection::InvalidEvent(e.to_string())
+    }
+}
+
+impl IntoResponse for EventRejection {
+    fn into_response(self) -> Response {
+        match self {
+            EventRejection::Body(e) => e.into_response(),
+            e => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+        }
+    }
+}
+
+impl CloudEvent {
+    pub fn into_inner(self) -> Event {
+        self.0
+    }
+}
+
+impl ops::Deref for CloudEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Event {
+        &self.0
+    }
+}
+
+impl HttpCloudEvent {
+    pub fn into_inner(self) -> http::HttpEvent {
+        self.0
+    }
+}
+
+impl<S: Send + Sync> FromRequest<S> for CloudEvent {
+    type Rejection = EventRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match extract(req, state).await?.0 {
+            http::HttpEvent::Binary(e) | http::HttpEvent::Structured(e) => Ok(CloudEvent(e)),
+            http::HttpEvent::Batch(_) => Err(EventRejection::UnexpectedBatch),
+        }
+    }
+}
+
+impl<S: Send + Sync> FromRequest<S> for HttpCloudEvent {
+    type Rejection = EventRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        extract(req, state).await.map(|(event, format)| HttpCloudEvent(event, format))
+    }
+}
+
+async fn extract<S: Send + Sync>(req: Request, state: &S) -> Result<(http::HttpEvent, http::EventFormat), EventRejection> {
+    let (parts, body) = req.into_parts();
+    let body = Bytes::from_request(Request::from_parts(parts.clone(), body), state)
+        .await
+        .map_err(EventRejection::Body)?;
+
+    HttpReader::read_cloud_event_with_format((parts.headers, body))?.ok_or(EventRejection::NoEvent)
+}
@@ -0,0 +1,28 @@
+use crate::{CloudEvent, HttpCloudEvent};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use cloudevents::http::{self, EventWriter as _, HttpWriter};
+
+/// Responds with the event in binary mode.
+///
+/// Events failing to be written are responded with 500 Internal Server Error.
+/// Use a `(StatusCode, CloudEvent)` tuple to respond with a status code other than 200 OK.
+impl IntoResponse for CloudEvent {
+    fn into_response(self) -> Response {
+        write(http::HttpEvent::Binary(self.0), http::EventFormat::Json)
+    }
+}
+
+/// Responds with the event in the mode of the wrapped [`http::HttpEvent`] and in the wrapped [`http::EventFormat`]
+impl IntoResponse for HttpCloudEvent {
+    fn into_response(self) -> Response {
+        write(self.0, self.1)
+    }
+}
+
+fn write(event: http::HttpEvent, format: http::EventFormat) -> Response {
+    match HttpWriter::write_cloud_event_with_format(event, format) {
+        Ok(res) => res,
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
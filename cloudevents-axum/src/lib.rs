@@ -0,0 +1,4 @@
+mod event_extractor;
+mod event_responder;
+
+pub use event_extractor::{CloudEvent, EventRejection, HttpCloudEvent};
@@ -0,0 +1,109 @@
+use axum::body::{to_bytes, Body};
+use axum::extract::DefaultBodyLimit;
+use axum::http::{Request, StatusCode};
+use axum::response::Response;
+use axum::routing::post;
+use axum::Router;
+use cloudevents::http::HttpEvent;
+use cloudevents_axum::{CloudEvent, HttpCloudEvent};
+use tower::ServiceExt;
+
+async fn echo_id(event: CloudEvent) -> String {
+    event.id.clone()
+}
+
+async fn echo_mode(event: HttpCloudEvent) -> &'static str {
+    match event.into_inner() {
+        HttpEvent::Binary(_) => "binary",
+        HttpEvent::Structured(_) => "structured",
+        HttpEvent::Batch(_) => "batch",
+    }
+}
+
+fn binary_request() -> axum::http::request::Builder {
+    Request::post("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "1.0")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("content-type", "application/json")
+}
+
+async fn call(app: Router, req: Request<Body>) -> (StatusCode, String) {
+    let res: Response = app.oneshot(req).await.unwrap();
+    let status = res.status();
+    let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn test_extract_binary_event() {
+    let app = Router::new().route("/", post(echo_id));
+
+    let req = binary_request().body(Body::from(r#"{"hello":"world"}"#)).unwrap();
+
+    assert_eq!(call(app, req).await, (StatusCode::OK, "A234-1234-1234".to_string()));
+}
+
+#[tokio::test]
+async fn test_extract_batch_http_event() {
+    let app = Router::new().route("/", post(echo_mode));
+
+    let req = Request::post("/")
+        .header("content-type", "application/cloudevents-batch+json")
+        .body(Body::from(r#"[{"id":"1","specversion":"1.0","type":"t","source":"/s"}]"#))
+        .unwrap();
+
+    assert_eq!(call(app, req).await, (StatusCode::OK, "batch".to_string()));
+}
+
+#[tokio::test]
+async fn test_reject_batch_for_single_event() {
+    let app = Router::new().route("/", post(echo_id));
+
+    let req = Request::post("/")
+        .header("content-type", "application/cloudevents-batch+json")
+        .body(Body::from("[]"))
+        .unwrap();
+
+    assert_eq!(call(app, req).await.0, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_reject_invalid_event_with_reader_error() {
+    let app = Router::new().route("/", post(echo_id));
+
+    let req = Request::post("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "9.9")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .body(Body::empty())
+        .unwrap();
+
+    assert_eq!(
+        call(app, req).await,
+        (
+            StatusCode::BAD_REQUEST,
+            "Invalid metadata 'specversion': Invalid specversion 9.9".to_string()
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_reject_request_without_event() {
+    let app = Router::new().route("/", post(echo_id));
+
+    let req = Request::post("/").body(Body::empty()).unwrap();
+
+    assert_eq!(call(app, req).await.0, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_reject_body_over_limit() {
+    let app = Router::new().route("/", post(echo_id)).layer(DefaultBodyLimit::max(8));
+
+    let req = binary_request().body(Body::from(r#"{"hello":"world"}"#)).unwrap();
+
+    assert_eq!(call(app, req).await.0, StatusCode::PAYLOAD_TOO_LARGE);
+}
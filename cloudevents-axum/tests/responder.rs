@@ -0,0 +1,95 @@
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use axum::routing::{get, post};
+use axum::Router;
+use cloudevents::http::{EventFormat, HttpEvent};
+use cloudevents::{EventBuilder, Payload};
+use cloudevents_axum::{CloudEvent, HttpCloudEvent};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_respond_binary_event() {
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello".as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+    let app = Router::new().route("/", get(|| async { CloudEvent(event) }));
+
+    let res = app.oneshot(Request::get("/").body(Body::empty()).unwrap()).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["ce-id"], "A234-1234-1234");
+    assert_eq!(res.headers()["content-type"], "text/plain");
+    assert_eq!(to_bytes(res.into_body(), usize::MAX).await.unwrap(), "hello");
+}
+
+#[tokio::test]
+async fn test_respond_structured_event_with_status() {
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .build()
+        .unwrap();
+    let app = Router::new().route(
+        "/",
+        get(|| async { (StatusCode::ACCEPTED, HttpCloudEvent(HttpEvent::Structured(event), EventFormat::Json)) }),
+    );
+
+    let res = app.oneshot(Request::get("/").body(Body::empty()).unwrap()).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    assert_eq!(res.headers()["content-type"], "application/cloudevents+json");
+    assert!(!res.headers().contains_key("ce-id"));
+}
+
+#[tokio::test]
+async fn test_echo_round_trip() {
+    let app = Router::new().route("/", post(|event: HttpCloudEvent| async { event }));
+
+    let req = Request::post("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "1.0")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("content-type", "text/plain")
+        .body(Body::from("hello"))
+        .unwrap();
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["ce-source"], "https://github.com/cloudevents/spec/pull");
+    assert_eq!(to_bytes(res.into_body(), usize::MAX).await.unwrap(), "hello");
+}
+
+#[tokio::test]
+async fn test_echo_protobuf_round_trip() {
+    let app = Router::new().route("/", post(|event: HttpCloudEvent| async { event }));
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello".as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+
+    let req = Request::post("/")
+        .header("content-type", "application/cloudevents+protobuf")
+        .body(Body::from(cloudevents::protobuf::to_vec(event.clone())))
+        .unwrap();
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "application/cloudevents+protobuf");
+    let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(cloudevents::protobuf::from_slice(&body).unwrap(), event);
+}
@@ -0,0 +1,89 @@
+use bytes::Bytes;
+use cloudevents::http::{self, EventReader as _, HttpReader};
+use http_body_util::{BodyExt, Limited};
+use hyper::body::Body;
+use hyper::{Request, Response};
+use std::error::Error;
+
+const DEFAULT_LIMIT: usize = 262_144;
+
+/// Reads events from hyper requests and responses, collecting the body up to a size limit.
+///
+/// Bodies larger than the limit are rejected with `http::ReaderError::Other` wrapping a [`LengthLimitError`](crate::LengthLimitError),
+/// which can be told apart from the other errors with `downcast_ref`.
+#[derive(Clone)]
+pub struct EventReader {
+    limit: usize,
+    strict: bool,
+}
+
+impl EventReader {
+    pub fn new() -> EventReader {
+        EventReader {
+            limit: DEFAULT_LIMIT,
+            strict: false,
+        }
+    }
+
+    /// Sets the maximum size in bytes of the body to collect, 256 KiB (262_144 bytes) by default
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Reads the events with [`http::EventReader::read_cloud_event_strict`],
+    /// rejecting the ones not conforming to the spec
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub async fn read_request<B>(&self, req: Request<B>) -> Result<Option<http::HttpEvent>, http::ReaderError>
+        where
+            B: Body,
+            B::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let (parts, body) = req.into_parts();
+        let body = self.collect(body).await?;
+        self.read(Request::from_parts(parts, body))
+    }
+
+    pub async fn read_response<B>(&self, res: Response<B>) -> Result<Option<http::HttpEvent>, http::ReaderError>
+        where
+            B: Body,
+            B::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let (parts, body) = res.into_parts();
+        let body = self.collect(body).await?;
+        self.read(Response::from_parts(parts, body))
+    }
+
+    fn read<R>(&self, r: R) -> Result<Option<http::HttpEvent>, http::ReaderError>
+        where
+            HttpReader: http::EventReader<R>,
+    {
+        if self.strict {
+            HttpReader::read_cloud_event_strict(r)
+        } else {
+            HttpReader::read_cloud_event(r)
+        }
+    }
+
+    async fn collect<B>(&self, body: B) -> Result<Bytes, http::ReaderError>
+        where
+            B: Body,
+            B::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        Limited::new(body, self.limit)
+            .collect()
+            .await
+            .map(|collected| collected.to_bytes())
+            .map_err(|e| http::ReaderError::Other(e))
+    }
+}
+
+impl Default for EventReader {
+    fn default() -> Self {
+        EventReader::new()
+    }
+}
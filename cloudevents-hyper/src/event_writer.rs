@@ -0,0 +1,21 @@
+use bytes::Bytes;
+use cloudevents::http::{self, HttpWriter};
+use http_body_util::Full;
+use hyper::{Request, Response};
+
+/// Writes events into hyper responses and into requests to be sent with a hyper client.
+///
+/// Requests are POST requests, with the uri left to be set by the caller.
+pub struct EventWriter {}
+
+impl http::EventWriter<Response<Full<Bytes>>> for EventWriter {
+    fn write_cloud_event(res: http::HttpEvent) -> Result<Response<Full<Bytes>>, http::WriterError> {
+        HttpWriter::write_cloud_event(res)
+    }
+}
+
+impl http::EventWriter<Request<Full<Bytes>>> for EventWriter {
+    fn write_cloud_event(res: http::HttpEvent) -> Result<Request<Full<Bytes>>, http::WriterError> {
+        HttpWriter::write_cloud_event(res)
+    }
+}
@@ -0,0 +1,6 @@
+mod event_reader;
+mod event_writer;
+
+pub use event_reader::EventReader;
+pub use event_writer::EventWriter;
+pub use http_body_util::LengthLimitError;
@@ -0,0 +1,145 @@
+use bytes::Bytes;
+use chrono::DateTime;
+use cloudevents::http::{EventWriter as _, HttpEvent, ReaderError};
+use cloudevents::{EventBuilder, Payload};
+use cloudevents_hyper::{EventReader, EventWriter, LengthLimitError};
+use http_body_util::Full;
+use hyper::body::Incoming;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use tokio::net::{TcpListener, TcpStream};
+
+async fn echo(req: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    let res = match EventReader::new().read_request(req).await {
+        Ok(Some(event)) => EventWriter::write_cloud_event(event).unwrap(),
+        Ok(None) => Response::builder().status(StatusCode::BAD_REQUEST).body(Full::default()).unwrap(),
+        Err(e) => Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Full::from(e.to_string()))
+            .unwrap(),
+    };
+    Ok(res)
+}
+
+async fn start_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(
+                hyper::server::conn::http1::Builder::new().serve_connection(TokioIo::new(stream), service_fn(echo)),
+            );
+        }
+    });
+    addr
+}
+
+async fn send(addr: SocketAddr, mut req: Request<Full<Bytes>>) -> Response<Incoming> {
+    let stream = TcpStream::connect(addr).await.unwrap();
+    let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream)).await.unwrap();
+    tokio::spawn(conn);
+
+    *req.uri_mut() = "/".parse().unwrap();
+    req.headers_mut().insert(hyper::header::HOST, addr.to_string().parse().unwrap());
+    sender.send_request(req).await.unwrap()
+}
+
+// Sends the event to the echo server, expecting to read it back unchanged from the response
+async fn assert_round_trip(event: HttpEvent) {
+    let addr = start_server().await;
+
+    let req = EventWriter::write_cloud_event(event.clone()).unwrap();
+    let res = send(addr, req).await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(EventReader::new().read_response(res).await.unwrap(), Some(event));
+}
+
+#[tokio::test]
+async fn test_round_trip_binary() {
+    let mut event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .time(DateTime::parse_from_rfc3339("2020-03-09T10:00:00+01:00").unwrap())
+        .payload(Payload {
+            content_type: "application/json".to_string(),
+            data: r#"{"hello":"world"}"#.as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+    event.extensions.insert("comexampleextension1".to_string(), "value".into());
+
+    assert_round_trip(HttpEvent::Binary(event)).await;
+}
+
+#[tokio::test]
+async fn test_round_trip_structured() {
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "application/json".to_string(),
+            data: r#"{"hello":"world"}"#.as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+
+    assert_round_trip(HttpEvent::Structured(event)).await;
+}
+
+#[tokio::test]
+async fn test_round_trip_batch() {
+    let event = |id: &str| {
+        EventBuilder::default()
+            .id(id)
+            .source("https://github.com/cloudevents/spec/pull")
+            .event_type("com.github.pull.create")
+            .build()
+            .unwrap()
+    };
+
+    assert_round_trip(HttpEvent::Batch(vec![event("1"), event("2")])).await;
+}
+
+#[tokio::test]
+async fn test_reject_body_over_limit() {
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello world".as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+    let req: Request<Full<Bytes>> = EventWriter::write_cloud_event(HttpEvent::Binary(event)).unwrap();
+
+    match EventReader::new().limit(8).read_request(req).await {
+        Err(ReaderError::Other(e)) if e.is::<LengthLimitError>() => {}
+        r => panic!("Expecting body limit error, found {:?}", r),
+    }
+}
+
+#[tokio::test]
+async fn test_strict_rejects_invalid_event() {
+    let mut invalid = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .build()
+        .unwrap();
+    invalid.extensions.insert("Invalid_Name".to_string(), "value".into());
+    let req: Request<Full<Bytes>> = EventWriter::write_cloud_event(HttpEvent::Structured(invalid)).unwrap();
+
+    match EventReader::new().strict(true).read_request(req).await {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "Invalid_Name"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
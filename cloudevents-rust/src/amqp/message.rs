@@ -0,0 +1,147 @@
+use super::{CE_AMQP_JMS_PROPERTY_PREFIX, CE_AMQP_PROPERTY_PREFIX};
+use crate::binding;
+use crate::http::{ReaderError, WriterError, CE_JSON_CONTENT_TYPE};
+use crate::{Event, ExtensionValue};
+use chrono::{TimeZone, Utc};
+use fe2o3_amqp_types::messaging::{AmqpValue, ApplicationProperties, Batch, Body, Data, Message, Properties};
+use fe2o3_amqp_types::primitives::{Binary, OrderedMap, SimpleValue, Timestamp, Value};
+use std::convert::TryFrom;
+
+/// AMQP message carrying an event, with the data in a single data section
+pub type AmqpMessage = Message<Body<Value>>;
+
+/// Encodes the event in binary mode: attributes in `cloudEvents:` prefixed application properties
+/// with typed values, `datacontenttype` in the content-type property and data as body
+pub fn write_binary(event: Event) -> Result<AmqpMessage, WriterError> {
+    let mut application_properties = OrderedMap::new();
+    for (name, value) in binding::typed_attributes(&event) {
+        application_properties.insert(format!("{}{}", CE_AMQP_PROPERTY_PREFIX, name), simple_value(value));
+    }
+
+    let (content_type, body) = match event.payload {
+        Some(p) => (Some(p.content_type), data_body(p.data)),
+        None => (None, Body::Empty),
+    };
+    Ok(message(content_type, Some(ApplicationProperties(application_properties)), body))
+}
+
+/// Encodes the event in structured mode, as `application/cloudevents+json` body
+pub fn write_structured(event: Event) -> Result<AmqpMessage, WriterError> {
+    let body = data_body(serde_json::to_vec(&event)?);
+    Ok(message(Some(CE_JSON_CONTENT_TYPE.to_string()), None, body))
+}
+
+/// Reads the event encoded in the message, either in binary or structured mode.
+///
+/// Returns `None` if the message doesn't carry an event.
+pub fn read_event(message: &AmqpMessage) -> Result<Option<Event>, ReaderError> {
+    let content_type = message
+        .properties
+        .as_ref()
+        .and_then(|p| p.content_type.as_ref())
+        .map(|ct| ct.to_string());
+    let data = match &message.body {
+        Body::Data(sections) => Some(sections.iter().flat_map(|d| d.0.iter().copied()).collect::<Vec<u8>>()),
+        // Empty bodies are encoded as a null amqp-value section
+        Body::Empty | Body::Value(AmqpValue(Value::Null)) => None,
+        _ => {
+            return Err(ReaderError::InvalidEncoding {
+                content_type: content_type.unwrap_or_default(),
+                reason: "Expecting data sections as body".to_string(),
+            })
+        }
+    };
+
+    if let Some(ct) = &content_type {
+        if ct.starts_with(CE_JSON_CONTENT_TYPE) {
+            let data = data.ok_or_else(|| ReaderError::InvalidEncoding {
+                content_type: ct.clone(),
+                reason: "No body but content type is not null".to_string(),
+            })?;
+            return Ok(Some(serde_json::from_slice(&data)?));
+        }
+    }
+
+    let attributes = match &message.application_properties {
+        Some(properties) => properties
+            .0
+            .iter()
+            .filter_map(|(name, value)| attribute_name(name).map(|name| (name, value)))
+            .map(|(name, value)| extension_value(&name, value).map(|v| (name, v)))
+            .collect::<Result<Vec<(String, ExtensionValue)>, ReaderError>>()?,
+        None => Vec::new(),
+    };
+    if !attributes.iter().any(|(name, _)| name == "specversion") {
+        return Ok(None);
+    }
+
+    let payload = binding::payload(content_type, data)?;
+    binding::event_from_attributes(attributes, payload).map(Some)
+}
+
+fn message(content_type: Option<String>, application_properties: Option<ApplicationProperties>, body: Body<Value>) -> AmqpMessage {
+    let properties = content_type.map(|ct| Properties {
+        content_type: Some(ct.into()),
+        ..Default::default()
+    });
+    Message {
+        header: None,
+        delivery_annotations: None,
+        message_annotations: None,
+        properties,
+        application_properties,
+        body,
+        footer: None,
+    }
+}
+
+fn data_body(data: Vec<u8>) -> Body<Value> {
+    Body::Data(Batch::new(vec![Data(Binary::from(data))]))
+}
+
+fn attribute_name(property_name: &str) -> Option<String> {
+    property_name
+        .strip_prefix(CE_AMQP_PROPERTY_PREFIX)
+        .or_else(|| property_name.strip_prefix(CE_AMQP_JMS_PROPERTY_PREFIX))
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+}
+
+// URIs and URI-references are carried as strings, as mandated by the AMQP binding
+fn simple_value(value: ExtensionValue) -> SimpleValue {
+    match value {
+        ExtensionValue::Boolean(b) => SimpleValue::Bool(b),
+        ExtensionValue::Integer(i) => SimpleValue::Int(i),
+        ExtensionValue::String(s) | ExtensionValue::UriRef(s) => SimpleValue::String(s),
+        ExtensionValue::Binary(b) => SimpleValue::Binary(Binary::from(b)),
+        ExtensionValue::Uri(u) => SimpleValue::String(u.to_string()),
+        ExtensionValue::Timestamp(t) => SimpleValue::Timestamp(Timestamp::from_milliseconds(t.timestamp_millis())),
+    }
+}
+
+fn extension_value(name: &str, value: &SimpleValue) -> Result<ExtensionValue, ReaderError> {
+    let unsupported = || ReaderError::InvalidMetadata {
+        name: name.to_string(),
+        reason: format!("Unsupported AMQP type of value {:?}", value),
+    };
+    Ok(match value {
+        SimpleValue::Bool(b) => ExtensionValue::Boolean(*b),
+        SimpleValue::Byte(i) => ExtensionValue::Integer(i32::from(*i)),
+        SimpleValue::Short(i) => ExtensionValue::Integer(i32::from(*i)),
+        SimpleValue::Int(i) => ExtensionValue::Integer(*i),
+        SimpleValue::Long(i) => ExtensionValue::Integer(i32::try_from(*i).map_err(|_| unsupported())?),
+        SimpleValue::Ubyte(i) => ExtensionValue::Integer(i32::from(*i)),
+        SimpleValue::Ushort(i) => ExtensionValue::Integer(i32::from(*i)),
+        SimpleValue::Uint(i) => ExtensionValue::Integer(i32::try_from(*i).map_err(|_| unsupported())?),
+        SimpleValue::String(s) => ExtensionValue::String(s.clone()),
+        SimpleValue::Symbol(s) => ExtensionValue::String(s.to_string()),
+        SimpleValue::Binary(b) => ExtensionValue::Binary(b.to_vec()),
+        SimpleValue::Timestamp(t) => ExtensionValue::Timestamp(
+            Utc.timestamp_millis_opt(t.milliseconds())
+                .single()
+                .map(Into::into)
+                .ok_or_else(unsupported)?,
+        ),
+        _ => return Err(unsupported()),
+    })
+}
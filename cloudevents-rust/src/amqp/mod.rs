@@ -0,0 +1,9 @@
+mod message;
+
+pub use message::{read_event, write_binary, write_structured, AmqpMessage};
+
+/// Prefix of the application properties carrying context attributes in binary mode
+pub const CE_AMQP_PROPERTY_PREFIX: &str = "cloudEvents:";
+
+/// Alternative prefix of the application properties, for the JMS compatible names
+pub const CE_AMQP_JMS_PROPERTY_PREFIX: &str = "cloudEvents_";
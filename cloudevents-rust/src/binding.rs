@@ -0,0 +1,153 @@
+//! Mapping of context attributes to string key/value pairs,
+//! shared by the protocol bindings carrying attributes as message headers or properties and by the Protobuf format
+
+use crate::http::ReaderError;
+use crate::{Event, ExtensionValue, Payload, SpecVersion};
+use chrono::DateTime;
+use std::convert::TryInto;
+use url::Url;
+
+/// Returns the attributes of the event as name/value pairs, with the values in their canonical string form
+#[cfg(any(feature = "kafka", feature = "mqtt", feature = "nats"))]
+pub(crate) fn attributes(event: &Event) -> Vec<(String, String)> {
+    typed_attributes(event)
+        .into_iter()
+        .map(|(name, value)| (name, value.to_string()))
+        .collect()
+}
+
+/// Returns the attributes of the event as name/value pairs, with `time` as timestamp and `dataschema` as URI,
+/// for the bindings supporting typed values
+pub(crate) fn typed_attributes(event: &Event) -> Vec<(String, ExtensionValue)> {
+    let mut attributes = vec![
+        ("specversion".to_string(), ExtensionValue::from(event.spec_version.to_string())),
+        ("id".to_string(), ExtensionValue::from(event.id.clone())),
+        ("source".to_string(), ExtensionValue::from(event.source.clone())),
+        ("type".to_string(), ExtensionValue::from(event.event_type.clone())),
+    ];
+    if let Some(sub) = &event.subject {
+        attributes.push(("subject".to_string(), ExtensionValue::from(sub.clone())));
+    }
+    if let Some(time) = &event.time {
+        attributes.push(("time".to_string(), ExtensionValue::from(*time)));
+    }
+    if let Some(ds) = &event.data_schema {
+        attributes.push((data_schema_name(&event.spec_version).to_string(), ExtensionValue::from(ds.clone())));
+    }
+    for (name, value) in &event.extensions {
+        attributes.push((name.clone(), value.clone()));
+    }
+    attributes
+}
+
+/// Builds an event from attribute name/value pairs and an optional content type and data.
+///
+/// Attributes not defined by the spec become extensions, keeping the type of their values.
+pub(crate) fn event_from_attributes<I, V>(attributes: I, payload: Option<(String, Vec<u8>)>) -> Result<Event, ReaderError>
+    where
+        I: IntoIterator<Item = (String, V)>,
+        V: Into<ExtensionValue>,
+{
+    let mut ce = Event::new();
+    let mut id = None;
+    let mut source = None;
+    let mut event_type = None;
+    let mut spec_version = None;
+    let mut data_schema = None;
+    let mut data_content_encoding = None;
+
+    for (name, value) in attributes {
+        let value: ExtensionValue = value.into();
+        if !is_spec_attribute(&name) {
+            ce.extensions.insert(name, value);
+            continue;
+        }
+        let value = value.to_string();
+        match name.as_str() {
+            "id" => id = Some(value),
+            "source" => source = Some(value),
+            "type" => event_type = Some(value),
+            "specversion" => spec_version = Some(value),
+            "subject" => ce.subject = Some(value),
+            "time" => {
+                ce.time = Some(DateTime::parse_from_rfc3339(&value).map_err(|e| invalid_metadata("time", e))?)
+            }
+            "dataschema" | "schemaurl" => data_schema = Some((name, value)),
+            // datacontentencoding
+            _ => data_content_encoding = Some(value),
+        }
+    }
+
+    ce.spec_version = required(spec_version, "specversion")?
+        .try_into()
+        .map_err(|e: String| invalid_metadata("specversion", e))?;
+    ce.id = required(id, "id")?;
+    ce.source = required(source, "source")?;
+    ce.event_type = required(event_type, "type")?;
+    // Spec 0.3 allows the data to be base64 encoded, in 1.0 datacontentencoding is just an extension
+    if ce.spec_version == SpecVersion::V10 {
+        if let Some(value) = data_content_encoding.take() {
+            ce.extensions.insert("datacontentencoding".to_string(), ExtensionValue::from(value));
+        }
+    }
+    if let Some((name, value)) = data_schema {
+        if name != data_schema_name(&ce.spec_version) {
+            ce.extensions.insert(name, ExtensionValue::from(value));
+        } else {
+            ce.data_schema = Some(Url::parse(&value).map_err(|e| invalid_metadata(&name, e))?);
+        }
+    }
+
+    if let Some((content_type, data)) = payload {
+        let data = match data_content_encoding {
+            Some(enc) if enc.eq_ignore_ascii_case("base64") => {
+                base64::decode(&data).map_err(|e| ReaderError::InvalidEncoding {
+                    content_type: content_type.clone(),
+                    reason: e.to_string(),
+                })?
+            }
+            Some(enc) => return Err(invalid_metadata("datacontentencoding", format!("Unsupported encoding {}", enc))),
+            None => data,
+        };
+        ce.payload = Some(Payload { content_type, data });
+    }
+
+    Ok(ce)
+}
+
+/// Pairs the data of a binary mode message with its content type.
+///
+/// Unlike the JSON format, there's no default content type to assume, so non empty data without one is rejected.
+#[cfg(any(feature = "kafka", feature = "mqtt", feature = "amqp", feature = "nats"))]
+pub(crate) fn payload(content_type: Option<String>, data: Option<Vec<u8>>) -> Result<Option<(String, Vec<u8>)>, ReaderError> {
+    match (content_type, data) {
+        (Some(content_type), Some(data)) => Ok(Some((content_type, data))),
+        (None, Some(data)) if !data.is_empty() => Err(invalid_metadata("datacontenttype", "Missing content type of the data")),
+        _ => Ok(None),
+    }
+}
+
+fn is_spec_attribute(name: &str) -> bool {
+    matches!(
+        name,
+        "id" | "source" | "type" | "specversion" | "subject" | "time" | "dataschema" | "schemaurl" | "datacontentencoding"
+    )
+}
+
+fn data_schema_name(spec_version: &SpecVersion) -> &'static str {
+    match spec_version {
+        SpecVersion::V03 => "schemaurl",
+        SpecVersion::V10 => "dataschema",
+    }
+}
+
+fn required(value: Option<String>, name: &str) -> Result<String, ReaderError> {
+    value.ok_or_else(|| invalid_metadata(name, "Missing required attribute"))
+}
+
+fn invalid_metadata(name: &str, reason: impl ToString) -> ReaderError {
+    ReaderError::InvalidMetadata {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    }
+}
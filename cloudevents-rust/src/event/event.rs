@@ -6,16 +6,17 @@ extern crate uuid;
 
 use chrono::{DateTime, FixedOffset};
 use hostname::get_hostname;
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use url::Url;
 use uuid::Uuid;
 use crate::event::SpecVersion;
 use crate::event::Payload;
+use crate::event::ExtensionValue;
 
 const DEFAULT_TYPE: &str = "generated.cloudevents-sdk";
 const DEFAULT_SOURCE: &str = "cloudevents.io";
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Builder)]
+#[derive(PartialEq, Debug, Clone, Builder)]
 #[builder(setter(into, strip_option))]
 pub struct Event {
     #[builder(default = "Uuid::new_v4().to_string()")]
@@ -25,33 +26,44 @@ pub struct Event {
     pub source: String,
 
     #[builder(default = "SpecVersion::V10")]
-    #[serde(rename = "specversion")]
     pub spec_version: SpecVersion,
 
     #[builder(default = "DEFAULT_TYPE.to_string()")]
-    #[serde(rename = "type")]
     pub event_type: String,
 
     #[builder(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub subject: Option<String>,
     #[builder(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub time: Option<DateTime<FixedOffset>>,
+    #[builder(default)]
+    pub data_schema: Option<Url>,
 
-    #[serde(flatten)]
     #[builder(default)]
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub payload: Option<Payload>,
 
-    #[serde(flatten)]
     #[builder(default)]
-    pub extensions: HashMap<String, String>,
+    pub extensions: HashMap<String, ExtensionValue>,
 }
 
 #[allow(non_snake_case)]
 impl Event {
     pub fn new() -> Event {
-        return EventBuilder::default().build().unwrap();
+        EventBuilder::default().build().unwrap()
+    }
+
+    /// Convert this event to the provided spec version.
+    ///
+    /// Context attributes are stored independently from the spec version,
+    /// so attributes renamed between versions (like 0.3 `schemaurl` and 1.0 `dataschema`)
+    /// are carried over, and formats and bindings write them with the names of the target version.
+    pub fn into_spec_version(mut self, spec_version: SpecVersion) -> Event {
+        self.spec_version = spec_version;
+        self
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Event::new()
     }
 }
@@ -0,0 +1,207 @@
+extern crate chrono;
+extern crate serde;
+extern crate url;
+
+use chrono::{DateTime, FixedOffset};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+use url::Url;
+
+/// Value of an extension attribute, following the CloudEvents type system.
+///
+/// JSON only preserves `Boolean`, `Integer` and `String` values: the other variants
+/// are written using their canonical string encoding and read back as `String`.
+/// The typed accessors parse the canonical string encoding too, so they can be used
+/// regardless of the format or binding the event was read from.
+#[derive(PartialEq, Debug, Clone)]
+pub enum ExtensionValue {
+    Boolean(bool),
+    Integer(i32),
+    String(String),
+    Binary(Vec<u8>),
+    Uri(Url),
+    UriRef(String),
+    Timestamp(DateTime<FixedOffset>),
+}
+
+impl ExtensionValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ExtensionValue::Boolean(b) => Some(*b),
+            ExtensionValue::String(s) => match s.as_str() {
+                "true" => Some(true),
+                "false" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i32> {
+        match self {
+            ExtensionValue::Integer(i) => Some(*i),
+            ExtensionValue::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ExtensionValue::String(s) | ExtensionValue::UriRef(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_binary(&self) -> Option<Vec<u8>> {
+        match self {
+            ExtensionValue::Binary(b) => Some(b.clone()),
+            ExtensionValue::String(s) => base64::decode(s).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_uri(&self) -> Option<Url> {
+        match self {
+            ExtensionValue::Uri(u) => Some(u.clone()),
+            ExtensionValue::String(s) | ExtensionValue::UriRef(s) => Url::parse(s).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_timestamp(&self) -> Option<DateTime<FixedOffset>> {
+        match self {
+            ExtensionValue::Timestamp(t) => Some(*t),
+            ExtensionValue::String(s) => DateTime::parse_from_rfc3339(s).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Canonical string encoding, as used by binary mode bindings
+impl fmt::Display for ExtensionValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtensionValue::Boolean(b) => write!(f, "{}", b),
+            ExtensionValue::Integer(i) => write!(f, "{}", i),
+            ExtensionValue::String(s) | ExtensionValue::UriRef(s) => f.write_str(s),
+            ExtensionValue::Binary(b) => f.write_str(&base64::encode(b)),
+            ExtensionValue::Uri(u) => f.write_str(u.as_str()),
+            ExtensionValue::Timestamp(t) => f.write_str(&t.to_rfc3339()),
+        }
+    }
+}
+
+impl From<bool> for ExtensionValue {
+    fn from(value: bool) -> Self {
+        ExtensionValue::Boolean(value)
+    }
+}
+
+impl From<i32> for ExtensionValue {
+    fn from(value: i32) -> Self {
+        ExtensionValue::Integer(value)
+    }
+}
+
+impl From<String> for ExtensionValue {
+    fn from(value: String) -> Self {
+        ExtensionValue::String(value)
+    }
+}
+
+impl From<&str> for ExtensionValue {
+    fn from(value: &str) -> Self {
+        ExtensionValue::String(value.to_string())
+    }
+}
+
+impl From<Vec<u8>> for ExtensionValue {
+    fn from(value: Vec<u8>) -> Self {
+        ExtensionValue::Binary(value)
+    }
+}
+
+impl From<Url> for ExtensionValue {
+    fn from(value: Url) -> Self {
+        ExtensionValue::Uri(value)
+    }
+}
+
+impl From<DateTime<FixedOffset>> for ExtensionValue {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        ExtensionValue::Timestamp(value)
+    }
+}
+
+impl Serialize for ExtensionValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        match self {
+            ExtensionValue::Boolean(b) => serializer.serialize_bool(*b),
+            ExtensionValue::Integer(i) => serializer.serialize_i32(*i),
+            _ => serializer.serialize_str(&self.to_string()),
+        }
+    }
+}
+
+struct ExtensionValueVisitor;
+
+impl<'de> Visitor<'de> for ExtensionValueVisitor {
+    type Value = ExtensionValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a boolean, a 32 bit integer or a string")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+    {
+        Ok(ExtensionValue::Boolean(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+    {
+        i32::try_from(v)
+            .map(ExtensionValue::Integer)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+    {
+        i32::try_from(v)
+            .map(ExtensionValue::Integer)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+    {
+        Ok(ExtensionValue::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+    {
+        Ok(ExtensionValue::String(v))
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtensionValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ExtensionValueVisitor)
+    }
+}
@@ -1,6 +1,15 @@
 extern crate serde_json;
 
-use crate::{Event, PayloadReader, PayloadWriter, PayloadResult, Payload};
+use crate::event::payload::is_json_content_type;
+use crate::{Event, ExtensionValue, PayloadReader, PayloadWriter, PayloadResult, Payload, SpecVersion};
+use chrono::DateTime;
+use serde::de;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use url::Url;
 
 impl PayloadWriter<serde_json::Value, serde_json::Error> for Event {
     fn write_payload(
@@ -21,14 +30,243 @@ impl PayloadReader<serde_json::Value, serde_json::Error> for Event {
     fn read_content_type_and_payload(
         &self,
     ) -> PayloadResult<(String, serde_json::Value), serde_json::Error> {
-        if self.payload.is_none() {
-            return None;
-        }
-
-        let p = self.payload.as_ref().unwrap();
+        let p = self.payload.as_ref()?;
         Some(
             serde_json::from_slice::<serde_json::Value>(&p.data[..])
                 .map(|j| (p.content_type.clone(), j)),
         )
     }
 }
+
+const SPECVERSION_FIELD: &str = "specversion";
+const ID_FIELD: &str = "id";
+const SOURCE_FIELD: &str = "source";
+const TYPE_FIELD: &str = "type";
+const SUBJECT_FIELD: &str = "subject";
+const TIME_FIELD: &str = "time";
+const DATASCHEMA_FIELD: &str = "dataschema";
+const SCHEMAURL_FIELD: &str = "schemaurl";
+const DATACONTENTTYPE_FIELD: &str = "datacontenttype";
+const DATACONTENTENCODING_FIELD: &str = "datacontentencoding";
+const DATA_FIELD: &str = "data";
+const DATA_BASE64_FIELD: &str = "data_base64";
+
+fn data_schema_field(spec_version: &SpecVersion) -> &'static str {
+    match spec_version {
+        SpecVersion::V03 => SCHEMAURL_FIELD,
+        SpecVersion::V10 => DATASCHEMA_FIELD,
+    }
+}
+
+// Payloads with a JSON content type holding valid JSON are embedded as JSON values in `data`,
+// strings included, other payloads that are valid UTF-8 are written as `data` string,
+// everything else is base64 encoded, in `data_base64` for 1.0 and
+// in `data` together with `datacontentencoding` for 0.3
+fn serialize_payload<M: SerializeMap>(
+    map: &mut M,
+    spec_version: &SpecVersion,
+    payload: &Payload,
+) -> Result<(), M::Error> {
+    map.serialize_entry(DATACONTENTTYPE_FIELD, &payload.content_type)?;
+    if is_json_content_type(&payload.content_type) {
+        if let Ok(value) = serde_json::from_slice::<Value>(&payload.data) {
+            return map.serialize_entry(DATA_FIELD, &value);
+        }
+    } else if let Ok(s) = std::str::from_utf8(&payload.data) {
+        return map.serialize_entry(DATA_FIELD, s);
+    }
+    match spec_version {
+        SpecVersion::V03 => {
+            map.serialize_entry(DATACONTENTENCODING_FIELD, "base64")?;
+            map.serialize_entry(DATA_FIELD, &base64::encode(&payload.data))
+        }
+        SpecVersion::V10 => map.serialize_entry(DATA_BASE64_FIELD, &base64::encode(&payload.data)),
+    }
+}
+
+impl Serialize for Event {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(SPECVERSION_FIELD, &self.spec_version)?;
+        map.serialize_entry(ID_FIELD, &self.id)?;
+        map.serialize_entry(SOURCE_FIELD, &self.source)?;
+        map.serialize_entry(TYPE_FIELD, &self.event_type)?;
+        if let Some(subject) = &self.subject {
+            map.serialize_entry(SUBJECT_FIELD, subject)?;
+        }
+        if let Some(time) = &self.time {
+            map.serialize_entry(TIME_FIELD, &time.to_rfc3339())?;
+        }
+        if let Some(data_schema) = &self.data_schema {
+            map.serialize_entry(data_schema_field(&self.spec_version), data_schema)?;
+        }
+        if let Some(payload) = &self.payload {
+            serialize_payload(&mut map, &self.spec_version, payload)?;
+        }
+        for (name, value) in &self.extensions {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+// Per JSON format spec, null attributes are treated as unset
+fn take_string<E: de::Error>(map: &mut Map<String, Value>, field: &'static str) -> Result<Option<String>, E> {
+    match map.remove(field) {
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(Value::Null) | None => Ok(None),
+        Some(v) => Err(E::custom(format!("expecting string for attribute {}, found {}", field, v))),
+    }
+}
+
+fn take_required_string<E: de::Error>(map: &mut Map<String, Value>, field: &'static str) -> Result<String, E> {
+    take_string(map, field)?.ok_or_else(|| E::missing_field(field))
+}
+
+fn take_payload<E: de::Error>(
+    map: &mut Map<String, Value>,
+    spec_version: &SpecVersion,
+) -> Result<Option<Payload>, E> {
+    let content_type = take_string(map, DATACONTENTTYPE_FIELD)?;
+    // Per JSON format spec, a missing datacontenttype implies application/json for data
+    let json = content_type.as_deref().is_none_or(is_json_content_type);
+
+    let data = match spec_version {
+        SpecVersion::V03 => {
+            let base64_encoded = match take_string::<E>(map, DATACONTENTENCODING_FIELD)? {
+                Some(enc) if enc.eq_ignore_ascii_case("base64") => true,
+                Some(enc) => return Err(E::custom(format!("unsupported datacontentencoding {}", enc))),
+                None => false,
+            };
+            if base64_encoded {
+                take_base64::<E>(map, DATA_FIELD)?
+            } else {
+                take_data(map, json)?
+            }
+        }
+        SpecVersion::V10 => match (take_data(map, json)?, take_base64::<E>(map, DATA_BASE64_FIELD)?) {
+            (Some(_), Some(_)) => {
+                return Err(E::custom("only one of data and data_base64 can be present"))
+            }
+            (data, None) | (None, data) => data,
+        },
+    };
+
+    Ok(data.map(|(data, base64_encoded)| Payload {
+        content_type: content_type.unwrap_or_else(|| {
+            if base64_encoded { "application/octet-stream" } else { "application/json" }.to_string()
+        }),
+        data,
+    }))
+}
+
+// Returns the decoded data, flagged as base64 encoded
+fn take_base64<E: de::Error>(map: &mut Map<String, Value>, field: &'static str) -> Result<Option<(Vec<u8>, bool)>, E> {
+    take_string::<E>(map, field)?
+        .map(|s| base64::decode(s).map(|data| (data, true)).map_err(E::custom))
+        .transpose()
+}
+
+// JSON values are kept as serialized JSON for JSON content types, while strings are the data itself for the others
+fn take_data<E: de::Error>(map: &mut Map<String, Value>, json: bool) -> Result<Option<(Vec<u8>, bool)>, E> {
+    match map.remove(DATA_FIELD) {
+        Some(Value::Null) | None => Ok(None),
+        Some(Value::String(s)) if !json => Ok(Some((s.into_bytes(), false))),
+        Some(v) => serde_json::to_vec(&v).map(|data| Some((data, false))).map_err(E::custom),
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let mut map = Map::<String, Value>::deserialize(deserializer)?;
+
+        let spec_version = SpecVersion::try_from(take_required_string::<D::Error>(&mut map, SPECVERSION_FIELD)?)
+            .map_err(de::Error::custom)?;
+        let id = take_required_string(&mut map, ID_FIELD)?;
+        let source = take_required_string(&mut map, SOURCE_FIELD)?;
+        let event_type = take_required_string(&mut map, TYPE_FIELD)?;
+        let subject = take_string(&mut map, SUBJECT_FIELD)?;
+        let time = take_string::<D::Error>(&mut map, TIME_FIELD)?
+            .map(|t| DateTime::parse_from_rfc3339(&t).map_err(de::Error::custom))
+            .transpose()?;
+        let data_schema = take_string::<D::Error>(&mut map, data_schema_field(&spec_version))?
+            .map(|ds| Url::parse(&ds).map_err(de::Error::custom))
+            .transpose()?;
+        let payload = take_payload(&mut map, &spec_version)?;
+        let extensions = map
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(name, value)| Ok((name, ExtensionValue::deserialize(value).map_err(de::Error::custom)?)))
+            .collect::<Result<HashMap<String, ExtensionValue>, D::Error>>()?;
+
+        Ok(Event {
+            id,
+            source,
+            spec_version,
+            event_type,
+            subject,
+            time,
+            data_schema,
+            payload,
+            extensions,
+        })
+    }
+}
+
+// Payload is serialized as the data attributes of a 1.0 event
+impl Serialize for Payload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        serialize_payload(&mut map, &SpecVersion::V10, self)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let mut map = Map::<String, Value>::deserialize(deserializer)?;
+        take_payload(&mut map, &SpecVersion::V10)?.ok_or_else(|| de::Error::missing_field(DATA_FIELD))
+    }
+}
+
+/// Deserializes an [`Event`] like its own `Deserialize` implementation,
+/// but rejects events failing [`Event::validate`]
+#[derive(PartialEq, Debug, Clone)]
+pub struct StrictEvent(pub Event);
+
+impl<'de> Deserialize<'de> for StrictEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+    {
+        let event = Event::deserialize(deserializer)?;
+        event.validate().map_err(|errors| {
+            de::Error::custom(
+                errors
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            )
+        })?;
+        Ok(StrictEvent(event))
+    }
+}
+
+impl From<StrictEvent> for Event {
+    fn from(e: StrictEvent) -> Self {
+        e.0
+    }
+}
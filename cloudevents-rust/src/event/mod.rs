@@ -1,9 +1,14 @@
+#[allow(clippy::module_inception)]
 pub(crate) mod event;
+pub(crate) mod extensions;
 pub(crate) mod payload;
 pub(crate) mod spec_version;
 pub(crate) mod json;
+pub(crate) mod validation;
 
-pub use event::Event;
+pub use event::{Event, EventBuilder};
+pub use extensions::ExtensionValue;
 pub use payload::{Payload, PayloadMapper, PayloadReader, PayloadWriter, PayloadResult};
 pub use spec_version::SpecVersion;
-pub use json::*;
+pub use json::StrictEvent;
+pub use validation::ValidationError;
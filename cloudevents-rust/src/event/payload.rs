@@ -4,55 +4,23 @@ extern crate serde;
 extern crate serde_json;
 extern crate uuid;
 
-use serde::{Deserialize, Serialize};
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct Payload {
-    #[serde(rename = "datacontenttype")]
     pub content_type: String,
-
-    #[serde(with = "bytes_to_string")]
     pub data: Vec<u8>,
 }
 
-mod bytes_to_string {
-    use serde::de::Visitor;
-    use serde::{de, ser, Deserializer, Serializer};
-    use std::fmt;
-
-    pub fn serialize<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-    {
-        serializer.serialize_str(std::str::from_utf8(data).map_err(ser::Error::custom)?)
-    }
-
-    struct BytesBufferVisitor;
-
-    impl<'de> Visitor<'de> for BytesBufferVisitor {
-        type Value = Vec<u8>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("a string")
-        }
-
-        fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-        {
-            Ok(value.into_bytes())
-        }
-    }
-
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
-        where
-            D: Deserializer<'de>,
-    {
-        deserializer.deserialize_str(BytesBufferVisitor {})
-    }
+/// Returns true if the provided content type is `application/json`, `text/json` or has a `+json` suffix
+pub(crate) fn is_json_content_type(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    media_type == "application/json" || media_type == "text/json" || media_type.ends_with("+json")
 }
 
-
 pub type PayloadResult<T, E> = Option<Result<T, E>>;
 
 pub trait PayloadWriter<T: Sized, E: std::error::Error>
@@ -95,9 +63,9 @@ impl<
         if let Some(Ok((ct, value))) = self.read_content_type_and_payload() {
             let mut new = self.clone();
             new.write_payload(&ct, f(value))?;
-            return Ok(new);
+            Ok(new)
         } else {
-            return Ok(self.clone());
+            Ok(self.clone())
         }
     }
 }
@@ -10,6 +10,8 @@ use std::fmt;
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum SpecVersion {
+    #[serde(rename = "0.3")]
+    V03,
     #[serde(rename = "1.0")]
     V10,
 }
@@ -17,6 +19,7 @@ pub enum SpecVersion {
 impl fmt::Display for SpecVersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            SpecVersion::V03 => write!(f, "0.3"),
             SpecVersion::V10 => write!(f, "1.0"),
         }
     }
@@ -27,6 +30,7 @@ impl TryFrom<String> for SpecVersion {
 
     fn try_from(value: String) -> Result<Self, String> {
         match value.as_str() {
+            "0.3" => Ok(SpecVersion::V03),
             "1.0" => Ok(SpecVersion::V10),
             _ => Err(format!("Invalid specversion {}", value)),
         }
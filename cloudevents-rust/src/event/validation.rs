@@ -0,0 +1,124 @@
+use crate::event::Event;
+use std::error::Error;
+use std::fmt;
+
+// Attribute names an extension can't shadow, across all supported spec versions
+const RESERVED_NAMES: &[&str] = &[
+    "id",
+    "source",
+    "specversion",
+    "type",
+    "subject",
+    "time",
+    "dataschema",
+    "schemaurl",
+    "datacontenttype",
+    "datacontentencoding",
+    "data",
+    "data_base64",
+];
+
+/// Violation of the spec found by [`Event::validate`]
+#[derive(PartialEq, Debug, Clone)]
+pub struct ValidationError {
+    pub attribute: String,
+    pub reason: String,
+}
+
+impl ValidationError {
+    fn new(attribute: &str, reason: &str) -> ValidationError {
+        ValidationError {
+            attribute: attribute.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid attribute '{}': {}", self.attribute, self.reason)
+    }
+}
+
+impl Error for ValidationError {}
+
+impl Event {
+    /// Check this event against the constraints of the spec,
+    /// returning all the violations found
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        if self.id.is_empty() {
+            errors.push(ValidationError::new("id", "must be a non-empty string"));
+        }
+        if self.source.is_empty() {
+            errors.push(ValidationError::new("source", "must be a non-empty string"));
+        } else if !is_uri_reference(&self.source) {
+            errors.push(ValidationError::new("source", "must be a URI-reference"));
+        }
+        if self.event_type.is_empty() {
+            errors.push(ValidationError::new("type", "must be a non-empty string"));
+        }
+        if let Some(subject) = &self.subject {
+            if subject.is_empty() {
+                errors.push(ValidationError::new("subject", "must be a non-empty string"));
+            }
+        }
+        if let Some(payload) = &self.payload {
+            if payload.content_type.is_empty() {
+                errors.push(ValidationError::new("datacontenttype", "must be a non-empty string"));
+            }
+        }
+
+        let mut extension_names: Vec<&String> = self.extensions.keys().collect();
+        extension_names.sort();
+        for name in extension_names {
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            {
+                errors.push(ValidationError::new(
+                    name,
+                    "extension names must consist of lower-case letters or digits",
+                ));
+            } else if RESERVED_NAMES.contains(&name.as_str()) {
+                errors.push(ValidationError::new(
+                    name,
+                    "extension names must not collide with context attribute names",
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+// Checks the characters allowed by RFC 3986 and that every `%` starts a valid escape
+fn is_uri_reference(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let escape = bytes.get(i + 1..i + 3);
+                if !escape.is_some_and(|e| e.iter().all(u8::is_ascii_hexdigit)) {
+                    return false;
+                }
+                i += 3;
+                continue;
+            }
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => {}
+            b'-' | b'.' | b'_' | b'~' => {}
+            b':' | b'/' | b'?' | b'#' | b'[' | b']' | b'@' => {}
+            b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' => {}
+            _ => return false,
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,37 @@
+//! `CloudEventService` of the [gRPC protocol binding](https://github.com/cloudevents/spec/blob/main/cloudevents/bindings/grpc-protocol-binding.md),
+//! with a [tonic](https://github.com/hyperium/tonic) client and server.
+//!
+//! The service is generated with `tonic-prost-build` from `proto/cloudevents_service.proto`
+//! by the `codegen` tool, like the messages of the [`protobuf`](crate::protobuf) format it exchanges events in.
+
+mod generated;
+
+pub use generated::cloud_event_service_client::CloudEventServiceClient;
+pub use generated::cloud_event_service_server::{CloudEventService, CloudEventServiceServer};
+pub use generated::PublishRequest;
+
+use crate::http::ReaderError;
+use crate::Event;
+use std::convert::TryFrom;
+
+impl From<Event> for PublishRequest {
+    fn from(event: Event) -> Self {
+        PublishRequest {
+            event: Some(event.into()),
+        }
+    }
+}
+
+/// Reads the event published with the request.
+///
+/// Returns `None` if the request doesn't carry an event.
+pub fn read_event(request: PublishRequest) -> Result<Option<Event>, ReaderError> {
+    request.event.map(Event::try_from).transpose()
+}
+
+/// Events failing to be read are rejected as invalid argument
+impl From<ReaderError> for tonic::Status {
+    fn from(e: ReaderError) -> Self {
+        tonic::Status::invalid_argument(e.to_string())
+    }
+}
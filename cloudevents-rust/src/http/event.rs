@@ -1,7 +1,57 @@
 use crate::Event;
 
+/// Event read from or to be written to an HTTP message, together with the mode of its encoding.
+///
+/// The format of `Structured` and `Batch` events is carried separately by [`EventFormat`],
+/// JSON unless specified otherwise.
+#[derive(PartialEq, Debug, Clone)]
 pub enum HttpEvent {
     Binary(Event),
     Structured(Event),
     Batch(Vec<Event>)
 }
+
+/// Format of the events encoded in structured and batch mode
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum EventFormat {
+    Json,
+    /// The [`protobuf`](crate::protobuf) format, enabling the `protobuf` feature
+    #[cfg(feature = "protobuf")]
+    Protobuf,
+}
+
+impl EventFormat {
+    /// Returns the content type of a single event in structured mode
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            EventFormat::Json => super::CE_JSON_CONTENT_TYPE,
+            #[cfg(feature = "protobuf")]
+            EventFormat::Protobuf => super::CE_PROTOBUF_CONTENT_TYPE,
+        }
+    }
+
+    /// Returns the content type of a batch of events
+    pub fn batch_content_type(&self) -> &'static str {
+        match self {
+            EventFormat::Json => super::CE_BATCH_JSON_CONTENT_TYPE,
+            #[cfg(feature = "protobuf")]
+            EventFormat::Protobuf => super::CE_BATCH_PROTOBUF_CONTENT_TYPE,
+        }
+    }
+
+    /// Returns the format of a structured or batch mode message with the provided `Content-Type`,
+    /// or `None` for binary mode messages and unsupported formats
+    pub fn from_content_type(content_type: &str) -> Option<EventFormat> {
+        if content_type.contains(super::CE_JSON_CONTENT_TYPE) || content_type.contains(super::CE_BATCH_JSON_CONTENT_TYPE) {
+            return Some(EventFormat::Json);
+        }
+        #[cfg(feature = "protobuf")]
+        if content_type.contains(super::CE_PROTOBUF_CONTENT_TYPE)
+            || content_type.contains(super::CE_BATCH_PROTOBUF_CONTENT_TYPE)
+        {
+            return Some(EventFormat::Protobuf);
+        }
+        None
+    }
+}
@@ -0,0 +1,35 @@
+use super::CE_HEADER_PREFIX;
+use percent_encoding::{percent_decode, utf8_percent_encode, AsciiSet, CONTROLS};
+use std::str::Utf8Error;
+
+// Space, double-quote, percent and everything outside of printable ASCII
+// must be percent-encoded, as defined by the HTTP protocol binding
+const HEADER_VALUE_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'%');
+
+/// Returns the binary mode header name for the provided attribute name
+pub fn header_name(attribute_name: &str) -> String {
+    format!("{}{}", CE_HEADER_PREFIX, attribute_name)
+}
+
+/// Returns the attribute name carried by the provided header, if the header is a `ce-` header.
+///
+/// Header names are case-insensitive, while attribute names are always lower-case.
+pub fn attribute_name(header_name: &str) -> Option<String> {
+    let header_name = header_name.to_ascii_lowercase();
+    header_name
+        .strip_prefix(CE_HEADER_PREFIX)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+}
+
+/// Percent-encodes the provided attribute value to be used as `ce-` header value
+pub fn encode_header_value(value: &str) -> String {
+    utf8_percent_encode(value, HEADER_VALUE_ENCODE_SET).to_string()
+}
+
+/// Decodes the value of a `ce-` header into the attribute value.
+///
+/// Non percent-encoded UTF-8 is accepted too, to be lenient with non-compliant senders.
+pub fn decode_header_value(value: &[u8]) -> Result<String, Utf8Error> {
+    percent_decode(value).decode_utf8().map(String::from)
+}
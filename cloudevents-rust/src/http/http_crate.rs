@@ -0,0 +1,115 @@
+use super::{EventFormat, EventReader, EventWriter, Headers, HttpEvent, ReaderError, WriterError};
+use ::http::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use ::http::{Method, Request, Response};
+use std::convert::TryFrom;
+
+/// Reads events from the types of the [`http`](https://docs.rs/http) crate:
+/// `Request<B>`, `Response<B>` and `(HeaderMap, B)`, where `B` is the collected body
+pub struct HttpReader {}
+
+/// Writes events into the types of the [`http`](https://docs.rs/http) crate:
+/// `Request<B>`, `Response<B>` and `(HeaderMap, Vec<u8>)`
+pub struct HttpWriter {}
+
+impl<B: AsRef<[u8]>> EventReader<Request<B>> for HttpReader {
+    fn read_cloud_event(req: Request<B>) -> Result<Option<HttpEvent>, ReaderError> {
+        let (parts, body) = req.into_parts();
+        super::read_event(&parts.headers, body.as_ref())
+    }
+
+    fn read_cloud_event_with_format(req: Request<B>) -> Result<Option<(HttpEvent, EventFormat)>, ReaderError> {
+        let (parts, body) = req.into_parts();
+        super::read_event_with_format(&parts.headers, body.as_ref())
+    }
+}
+
+impl<B: AsRef<[u8]>> EventReader<Response<B>> for HttpReader {
+    fn read_cloud_event(res: Response<B>) -> Result<Option<HttpEvent>, ReaderError> {
+        let (parts, body) = res.into_parts();
+        super::read_event(&parts.headers, body.as_ref())
+    }
+
+    fn read_cloud_event_with_format(res: Response<B>) -> Result<Option<(HttpEvent, EventFormat)>, ReaderError> {
+        let (parts, body) = res.into_parts();
+        super::read_event_with_format(&parts.headers, body.as_ref())
+    }
+}
+
+impl<B: AsRef<[u8]>> EventReader<(HeaderMap, B)> for HttpReader {
+    fn read_cloud_event(r: (HeaderMap, B)) -> Result<Option<HttpEvent>, ReaderError> {
+        let (headers, body) = r;
+        super::read_event(&headers, body.as_ref())
+    }
+
+    fn read_cloud_event_with_format(r: (HeaderMap, B)) -> Result<Option<(HttpEvent, EventFormat)>, ReaderError> {
+        let (headers, body) = r;
+        super::read_event_with_format(&headers, body.as_ref())
+    }
+}
+
+impl<B: From<Vec<u8>>> EventWriter<Response<B>> for HttpWriter {
+    fn write_cloud_event(res: HttpEvent) -> Result<Response<B>, WriterError> {
+        Self::write_cloud_event_with_format(res, EventFormat::Json)
+    }
+
+    fn write_cloud_event_with_format(res: HttpEvent, format: EventFormat) -> Result<Response<B>, WriterError> {
+        let (headers, body) = write(res, format)?;
+        let mut response = Response::new(B::from(body));
+        *response.headers_mut() = headers;
+        Ok(response)
+    }
+}
+
+// Requests are POSTed, as mandated by the HTTP protocol binding
+impl<B: From<Vec<u8>>> EventWriter<Request<B>> for HttpWriter {
+    fn write_cloud_event(res: HttpEvent) -> Result<Request<B>, WriterError> {
+        Self::write_cloud_event_with_format(res, EventFormat::Json)
+    }
+
+    fn write_cloud_event_with_format(res: HttpEvent, format: EventFormat) -> Result<Request<B>, WriterError> {
+        let (headers, body) = write(res, format)?;
+        let mut request = Request::new(B::from(body));
+        *request.method_mut() = Method::POST;
+        *request.headers_mut() = headers;
+        Ok(request)
+    }
+}
+
+impl EventWriter<(HeaderMap, Vec<u8>)> for HttpWriter {
+    fn write_cloud_event(res: HttpEvent) -> Result<(HeaderMap, Vec<u8>), WriterError> {
+        write(res, EventFormat::Json)
+    }
+
+    fn write_cloud_event_with_format(res: HttpEvent, format: EventFormat) -> Result<(HeaderMap, Vec<u8>), WriterError> {
+        write(res, format)
+    }
+}
+
+impl Headers for HeaderMap {
+    fn get(&self, name: &str) -> Option<&[u8]> {
+        HeaderMap::get(self, name).map(HeaderValue::as_bytes)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (&str, &[u8])> + '_> {
+        Box::new(HeaderMap::iter(self).map(|(name, value)| (name.as_str(), value.as_bytes())))
+    }
+}
+
+fn write(res: HttpEvent, format: EventFormat) -> Result<(HeaderMap, Vec<u8>), WriterError> {
+    let encoded = super::write_event_with_format(res, format)?;
+    let mut headers = HeaderMap::new();
+    for (name, value) in &encoded.headers {
+        insert_header(&mut headers, name, value)?;
+    }
+    if let Some(ct) = &encoded.content_type {
+        insert_header(&mut headers, CONTENT_TYPE.as_str(), ct)?;
+    }
+    Ok((headers, encoded.body))
+}
+
+fn insert_header(headers: &mut HeaderMap, key: &str, value: &str) -> Result<(), WriterError> {
+    let name = HeaderName::try_from(key).map_err(|e| WriterError::Other(Box::new(e)))?;
+    let value = HeaderValue::try_from(value).map_err(|e| WriterError::Other(Box::new(e)))?;
+    headers.insert(name, value);
+    Ok(())
+}
@@ -0,0 +1,276 @@
+use super::{EventFormat, HttpEvent, ReaderError, WriterError};
+use crate::{Event, ExtensionValue, Payload, SpecVersion};
+use chrono::DateTime;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use url::Url;
+
+const CONTENT_TYPE_HEADER: &str = "content-type";
+
+/// Read access to the headers of an HTTP message, implemented by the integrations
+/// for the header map of their HTTP library to read events with [`read_event`]
+pub trait Headers {
+    /// Returns the value of the header with the provided lower-case name, if any
+    fn get(&self, name: &str) -> Option<&[u8]>;
+
+    /// Returns all the headers, as name and value pairs
+    fn iter(&self) -> Box<dyn Iterator<Item = (&str, &[u8])> + '_>;
+}
+
+/// Headers and body of an event encoded with [`write_event`]
+#[derive(PartialEq, Debug, Clone)]
+pub struct EncodedEvent {
+    /// Headers other than `Content-Type`, as name and value pairs
+    pub headers: Vec<(String, String)>,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+fn unwrap_header<H: Headers>(headers: &H, key: &str, meta_name: &str) -> Result<String, ReaderError> {
+    headers
+        .get(key)
+        .ok_or_else(|| ReaderError::InvalidMetadata {
+            name: meta_name.to_string(),
+            reason: format!("Expecting header {}", key),
+        })
+        .and_then(|ce| {
+            std::str::from_utf8(ce).map(String::from).map_err(|e| ReaderError::InvalidMetadata {
+                name: meta_name.to_string(),
+                reason: format!("Error while parsing header {}: {}", key, e),
+            })
+        })
+}
+
+// Like unwrap_header, but decodes the percent-encoded value of ce- headers
+fn unwrap_ce_header<H: Headers>(headers: &H, key: &str, meta_name: &str) -> Result<String, ReaderError> {
+    headers
+        .get(key)
+        .ok_or_else(|| ReaderError::InvalidMetadata {
+            name: meta_name.to_string(),
+            reason: format!("Expecting header {}", key),
+        })
+        .and_then(|ce| {
+            super::decode_header_value(ce).map_err(|e| ReaderError::InvalidMetadata {
+                name: meta_name.to_string(),
+                reason: format!("Error while decoding header {}: {}", key, e),
+            })
+        })
+}
+
+/// Reads the event encoded in the headers and body of an HTTP message, following this decision tree:
+///
+/// 1. Content-type exists:
+///    1. If the structured content type of a supported [`EventFormat`], e.g. `application/cloudevents+json` -> parse structured
+///    2. If the batch content type of a supported [`EventFormat`], e.g. `application/cloudevents-batch+json` -> parse batch
+///    3. If other -> parse binary
+/// 2. Content-type doesn't exist:
+///    1. If CE id header, then it's an empty payload cloud event -> parse binary
+///    2. If no CE header -> `None`
+pub fn read_event<H: Headers>(headers: &H, payload: &[u8]) -> Result<Option<HttpEvent>, ReaderError> {
+    Ok(read_event_with_format(headers, payload)?.map(|(event, _)| event))
+}
+
+/// Like [`read_event`], but also returns the format of structured and batch mode events,
+/// to write them back in the same format. Binary mode events are reported with the JSON format.
+pub fn read_event_with_format<H: Headers>(
+    headers: &H,
+    payload: &[u8],
+) -> Result<Option<(HttpEvent, EventFormat)>, ReaderError> {
+    if let Ok(ct) = unwrap_header(headers, CONTENT_TYPE_HEADER, "datacontenttype") {
+        // Payload at this point can't be None
+        if payload.is_empty() {
+            return Err(ReaderError::InvalidEncoding {
+                content_type: ct,
+                reason: "No body but content type is not null".to_string(),
+            });
+        }
+
+        // Try structured, batch and binary
+        if let Some(format) = EventFormat::from_content_type(&ct) {
+            let batch = ct.contains(format.batch_content_type());
+            return read_structured(format, batch, payload).map(|event| Some((event, format)));
+        } else if headers.get(super::CE_ID_HEADER).is_some() {
+            return Ok(Some((HttpEvent::Binary(parse_binary(headers, Some((ct, payload)))?), EventFormat::Json)));
+        } else {
+            return Err(ReaderError::InvalidEncoding {
+                content_type: ct,
+                reason: "Unrecognized encoding".to_string(),
+            });
+        }
+    }
+
+    // Empty payload event
+    if headers.get(super::CE_ID_HEADER).is_some() {
+        return Ok(Some((HttpEvent::Binary(parse_binary(headers, None)?), EventFormat::Json)));
+    }
+
+    Ok(None)
+}
+
+fn read_structured(format: EventFormat, batch: bool, payload: &[u8]) -> Result<HttpEvent, ReaderError> {
+    Ok(match (format, batch) {
+        (EventFormat::Json, false) => HttpEvent::Structured(serde_json::from_slice(payload)?),
+        (EventFormat::Json, true) => HttpEvent::Batch(serde_json::from_slice(payload)?),
+        #[cfg(feature = "protobuf")]
+        (EventFormat::Protobuf, false) => HttpEvent::Structured(crate::protobuf::from_slice(payload)?),
+        #[cfg(feature = "protobuf")]
+        (EventFormat::Protobuf, true) => HttpEvent::Batch(crate::protobuf::batch_from_slice(payload)?),
+    })
+}
+
+fn parse_binary<H: Headers>(headers: &H, payload: Option<(String, &[u8])>) -> Result<Event, ReaderError> {
+    let mut ce = Event::new();
+    read_ce_headers(headers, &mut ce)?;
+
+    // Spec 0.3 allows the body to be base64 encoded, in 1.0 datacontentencoding is just an extension
+    let base64_encoded = match ce.spec_version {
+        SpecVersion::V03 => match unwrap_ce_header(headers, super::CE_DATACONTENTENCODING_HEADER, "datacontentencoding") {
+            Ok(enc) if enc.eq_ignore_ascii_case("base64") => true,
+            Ok(enc) => {
+                return Err(ReaderError::InvalidMetadata {
+                    name: "datacontentencoding".to_string(),
+                    reason: format!("Unsupported encoding {}", enc),
+                })
+            }
+            Err(_) => false,
+        },
+        SpecVersion::V10 => false,
+    };
+
+    if let Some((ct, p)) = payload {
+        let data = if base64_encoded {
+            base64::decode(p).map_err(|e| ReaderError::InvalidEncoding {
+                content_type: ct.clone(),
+                reason: e.to_string(),
+            })?
+        } else {
+            p.to_vec()
+        };
+        ce.payload = Some(Payload {
+            content_type: ct,
+            data,
+        });
+    }
+
+    Ok(ce)
+}
+
+fn read_ce_headers<H: Headers>(headers: &H, ce: &mut Event) -> Result<(), ReaderError> {
+    ce.id = unwrap_ce_header(headers, super::CE_ID_HEADER, "id")?;
+    ce.event_type = unwrap_ce_header(headers, super::CE_TYPE_HEADER, "type")?;
+    ce.spec_version = unwrap_ce_header(headers, super::CE_SPECVERSION_HEADER, "specversion").and_then(|sv| {
+        sv.try_into()
+            .map_err(|e| ReaderError::InvalidMetadata {name: "specversion".to_string(), reason: e})
+    })?;
+    ce.source = unwrap_ce_header(headers, super::CE_SOURCE_HEADER, "source")?;
+    ce.subject = unwrap_ce_header(headers, super::CE_SUBJECT_HEADER, "subject").ok();
+    ce.time = unwrap_ce_header(headers, super::CE_TIME_HEADER, "time")
+        .and_then(|t| {
+            DateTime::parse_from_rfc3339(&t)
+                .map_err(|e| ReaderError::InvalidMetadata {name: "time".to_string(), reason: e.to_string()})
+        })
+        .ok();
+    let (data_schema_header, data_schema_name) = match ce.spec_version {
+        SpecVersion::V03 => (super::CE_SCHEMAURL_HEADER, "schemaurl"),
+        SpecVersion::V10 => (super::CE_DATASCHEMA_HEADER, "dataschema"),
+    };
+    ce.data_schema = match unwrap_ce_header(headers, data_schema_header, data_schema_name) {
+        Ok(ds) => Some(Url::parse(&ds).map_err(|e| ReaderError::InvalidMetadata {
+            name: data_schema_name.to_string(),
+            reason: e.to_string(),
+        })?),
+        Err(_) => None,
+    };
+
+    // All the other ce- headers are extensions
+    let mut read_headers = vec![
+        super::CE_ID_HEADER,
+        super::CE_TYPE_HEADER,
+        super::CE_SPECVERSION_HEADER,
+        super::CE_SOURCE_HEADER,
+        super::CE_SUBJECT_HEADER,
+        super::CE_TIME_HEADER,
+        data_schema_header,
+    ];
+    if ce.spec_version == SpecVersion::V03 {
+        read_headers.push(super::CE_DATACONTENTENCODING_HEADER);
+    }
+    ce.extensions = headers
+        .iter()
+        .filter(|(name, _)| !read_headers.iter().any(|h| h.eq_ignore_ascii_case(name)))
+        .filter_map(|(name, value)| super::attribute_name(name).map(|name| (name, value)))
+        .map(|(name, value)| {
+            super::decode_header_value(value)
+                .map(|v| (name.clone(), ExtensionValue::from(v)))
+                .map_err(|e| ReaderError::InvalidMetadata {name, reason: e.to_string()})
+        })
+        .collect::<Result<HashMap<String, ExtensionValue>, ReaderError>>()?;
+
+    Ok(())
+}
+
+/// Encodes the event into the headers and body of an HTTP message, using the JSON format in structured and batch mode
+pub fn write_event(event: HttpEvent) -> Result<EncodedEvent, WriterError> {
+    write_event_with_format(event, EventFormat::Json)
+}
+
+/// Like [`write_event`], but encoding structured and batch mode events in the provided format
+pub fn write_event_with_format(event: HttpEvent, format: EventFormat) -> Result<EncodedEvent, WriterError> {
+    match (event, format) {
+        (HttpEvent::Binary(e), _) => Ok(write_binary(e)),
+        (HttpEvent::Structured(e), EventFormat::Json) => Ok(body(serde_json::to_vec(&e)?, format.content_type())),
+        (HttpEvent::Batch(vec), EventFormat::Json) => Ok(body(serde_json::to_vec(&vec)?, format.batch_content_type())),
+        #[cfg(feature = "protobuf")]
+        (HttpEvent::Structured(e), EventFormat::Protobuf) => Ok(body(crate::protobuf::to_vec(e), format.content_type())),
+        #[cfg(feature = "protobuf")]
+        (HttpEvent::Batch(vec), EventFormat::Protobuf) => {
+            Ok(body(crate::protobuf::batch_to_vec(vec), format.batch_content_type()))
+        }
+    }
+}
+
+fn write_binary(event: Event) -> EncodedEvent {
+    let mut headers = Vec::new();
+    let mut push = |name: &str, value: &str| headers.push((name.to_string(), super::encode_header_value(value)));
+    push(super::CE_ID_HEADER, &event.id);
+    push(super::CE_SPECVERSION_HEADER, &event.spec_version.to_string());
+    push(super::CE_SOURCE_HEADER, &event.source);
+    push(super::CE_TYPE_HEADER, &event.event_type);
+    if let Some(sub) = &event.subject {
+        push(super::CE_SUBJECT_HEADER, sub);
+    }
+    if let Some(time) = &event.time {
+        push(super::CE_TIME_HEADER, &time.to_rfc3339());
+    }
+    if let Some(ds) = &event.data_schema {
+        let data_schema_header = match event.spec_version {
+            SpecVersion::V03 => super::CE_SCHEMAURL_HEADER,
+            SpecVersion::V10 => super::CE_DATASCHEMA_HEADER,
+        };
+        push(data_schema_header, ds.as_str());
+    }
+    for (name, value) in &event.extensions {
+        push(&super::header_name(name), &value.to_string());
+    }
+
+    match event.payload {
+        Some(p) => EncodedEvent {
+            headers,
+            content_type: Some(p.content_type),
+            body: p.data,
+        },
+        None => EncodedEvent {
+            headers,
+            content_type: None,
+            body: Vec::new(),
+        },
+    }
+}
+
+fn body(body: Vec<u8>, content_type: &str) -> EncodedEvent {
+    EncodedEvent {
+        headers: Vec::new(),
+        content_type: Some(content_type.to_string()),
+        body,
+    }
+}
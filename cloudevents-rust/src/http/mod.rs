@@ -1,17 +1,39 @@
 mod event;
+mod headers;
+#[cfg(feature = "http")]
+mod http_crate;
+mod message;
 mod reader;
+#[cfg(feature = "reqwest")]
+mod reqwest_sender;
+mod sender;
 mod writer;
 
-pub use event::HttpEvent;
+pub use event::{EventFormat, HttpEvent};
+pub use headers::{attribute_name, decode_header_value, encode_header_value, header_name};
+#[cfg(feature = "http")]
+pub use http_crate::{HttpReader, HttpWriter};
+pub use message::{read_event, read_event_with_format, write_event, write_event_with_format, EncodedEvent, Headers};
 pub use reader::{ReaderError, Reader as EventReader};
+#[cfg(feature = "reqwest")]
+pub use reqwest_sender::ReqwestSender;
+pub use sender::SenderError;
 pub use writer::{WriterError, Writer as EventWriter};
 
+/// Prefix of the headers carrying context attributes in binary mode
+pub const CE_HEADER_PREFIX: &str = "ce-";
+
 pub const CE_ID_HEADER: &str = "ce-id";
 pub const CE_TYPE_HEADER: &str = "ce-type";
 pub const CE_SOURCE_HEADER: &str = "ce-source";
 pub const CE_SPECVERSION_HEADER: &str = "ce-specversion";
 pub const CE_SUBJECT_HEADER: &str = "ce-subject";
 pub const CE_TIME_HEADER: &str = "ce-time";
+pub const CE_DATASCHEMA_HEADER: &str = "ce-dataschema";
+pub const CE_SCHEMAURL_HEADER: &str = "ce-schemaurl";
+pub const CE_DATACONTENTENCODING_HEADER: &str = "ce-datacontentencoding";
 
 pub const CE_JSON_CONTENT_TYPE: &str = "application/cloudevents+json";
 pub const CE_BATCH_JSON_CONTENT_TYPE: &str = "application/cloudevents-batch+json";
+pub const CE_PROTOBUF_CONTENT_TYPE: &str = "application/cloudevents+protobuf";
+pub const CE_BATCH_PROTOBUF_CONTENT_TYPE: &str = "application/cloudevents-batch+protobuf";
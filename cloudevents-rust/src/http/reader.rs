@@ -1,4 +1,5 @@
-use super::HttpEvent;
+use super::{EventFormat, HttpEvent};
+use crate::{Event, ValidationError};
 use std::fmt;
 use std::error::Error;
 
@@ -10,7 +11,7 @@ pub enum ReaderError {
 
 impl fmt::Display for ReaderError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        return match self {
+        match self {
             ReaderError::InvalidMetadata {name, reason} => write!(f, "Invalid metadata '{}': {}", name, reason),
             ReaderError::InvalidEncoding {content_type, reason} => write!(f, "Invalid encoding with Content-Type header '{}': {}", content_type, reason),
             ReaderError::Other(e) => e.fmt(f)
@@ -20,13 +21,13 @@ impl fmt::Display for ReaderError {
 
 impl fmt::Debug for ReaderError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", &self)
+        write!(f, "{}", self)
     }
 }
 
 impl Error for ReaderError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        return match self {
+        match self {
             ReaderError::Other(b) => Some(b.as_ref()),
             _ => None
         }
@@ -39,6 +40,60 @@ impl std::convert::From<serde_json::Error> for ReaderError {
     }
 }
 
+#[cfg(feature = "protobuf")]
+impl std::convert::From<prost::DecodeError> for ReaderError {
+    fn from(e: prost::DecodeError) -> Self {
+        ReaderError::Other(Box::new(e))
+    }
+}
+
+impl std::convert::From<ValidationError> for ReaderError {
+    fn from(e: ValidationError) -> Self {
+        ReaderError::InvalidMetadata {name: e.attribute, reason: e.reason}
+    }
+}
+
 pub trait Reader<R> {
     fn read_cloud_event(req: R) -> Result<Option<HttpEvent>, ReaderError>;
+
+    /// Like `read_cloud_event`, but rejects events that don't conform to the spec,
+    /// reporting the first violation as `ReaderError::InvalidMetadata`
+    fn read_cloud_event_strict(req: R) -> Result<Option<HttpEvent>, ReaderError> {
+        let event = Self::read_cloud_event(req)?;
+        if let Some(e) = &event {
+            validate_http_event(e)?;
+        }
+        Ok(event)
+    }
+
+    /// Like `read_cloud_event`, but also returns the format of structured and batch mode events,
+    /// see [`read_event_with_format`](super::read_event_with_format).
+    ///
+    /// Readers not overriding it report the JSON format.
+    fn read_cloud_event_with_format(req: R) -> Result<Option<(HttpEvent, EventFormat)>, ReaderError> {
+        Ok(Self::read_cloud_event(req)?.map(|event| (event, EventFormat::Json)))
+    }
+
+    /// Like `read_cloud_event_with_format`, but rejects events that don't conform to the spec,
+    /// as `read_cloud_event_strict`
+    fn read_cloud_event_with_format_strict(req: R) -> Result<Option<(HttpEvent, EventFormat)>, ReaderError> {
+        let event = Self::read_cloud_event_with_format(req)?;
+        if let Some((e, _)) = &event {
+            validate_http_event(e)?;
+        }
+        Ok(event)
+    }
+}
+
+fn validate_http_event(event: &HttpEvent) -> Result<(), ReaderError> {
+    match event {
+        HttpEvent::Binary(e) | HttpEvent::Structured(e) => validate(e),
+        HttpEvent::Batch(events) => events.iter().try_for_each(validate),
+    }
+}
+
+fn validate(event: &Event) -> Result<(), ReaderError> {
+    event
+        .validate()
+        .map_err(|mut errors| ReaderError::from(errors.remove(0)))
 }
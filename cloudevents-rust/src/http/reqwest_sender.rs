@@ -0,0 +1,48 @@
+use super::{EventReader, EventWriter, HttpEvent, HttpReader, HttpWriter, SenderError};
+use reqwest::Client;
+
+/// Sends events to a sink with [`reqwest`](https://docs.rs/reqwest).
+///
+/// The `reqwest` feature enables reqwest with the `rustls-tls` backend, so `https://` sinks are supported.
+pub struct ReqwestSender {
+    client: Client,
+    sink: String,
+}
+
+impl ReqwestSender {
+    pub fn new<S: Into<String>>(client: Client, sink: S) -> ReqwestSender {
+        ReqwestSender {
+            client,
+            sink: sink.into(),
+        }
+    }
+
+    /// POSTs the event to the sink, returning the event the sink replied with, if any.
+    ///
+    /// Replies with a non 2xx status code are reported as `SenderError::InvalidStatus`
+    pub async fn send(&self, event: HttpEvent) -> Result<Option<HttpEvent>, SenderError> {
+        let req: ::http::Request<Vec<u8>> = HttpWriter::write_cloud_event(event)?;
+        let (parts, body) = req.into_parts();
+
+        let response = self
+            .client
+            .post(&self.sink)
+            .headers(parts.headers)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| SenderError::Other(Box::new(e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(SenderError::InvalidStatus {status: status.as_u16()});
+        }
+
+        let headers = response.headers().clone();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| SenderError::Other(Box::new(e)))?;
+        Ok(HttpReader::read_cloud_event((headers, body))?)
+    }
+}
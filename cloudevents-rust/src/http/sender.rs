@@ -0,0 +1,50 @@
+use super::{ReaderError, WriterError};
+use std::error::Error;
+use std::fmt;
+
+pub enum SenderError {
+    Writer(WriterError),
+    Reader(ReaderError),
+    InvalidStatus {status: u16},
+    Other(Box<dyn Error>)
+}
+
+impl fmt::Display for SenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SenderError::Writer(e) => write!(f, "Error while writing the request: {}", e),
+            SenderError::Reader(e) => write!(f, "Error while reading the response: {}", e),
+            SenderError::InvalidStatus {status} => write!(f, "Sink replied with status code {}", status),
+            SenderError::Other(e) => e.fmt(f)
+        }
+    }
+}
+
+impl fmt::Debug for SenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Error for SenderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SenderError::Writer(e) => Some(e),
+            SenderError::Reader(e) => Some(e),
+            SenderError::Other(b) => Some(b.as_ref()),
+            _ => None
+        }
+    }
+}
+
+impl std::convert::From<WriterError> for SenderError {
+    fn from(e: WriterError) -> Self {
+        SenderError::Writer(e)
+    }
+}
+
+impl std::convert::From<ReaderError> for SenderError {
+    fn from(e: ReaderError) -> Self {
+        SenderError::Reader(e)
+    }
+}
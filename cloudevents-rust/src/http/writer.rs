@@ -1,4 +1,4 @@
-use super::HttpEvent;
+use super::{EventFormat, HttpEvent};
 use std::fmt;
 use std::error::Error;
 
@@ -8,7 +8,7 @@ pub enum WriterError {
 
 impl fmt::Display for WriterError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        return match self {
+        match self {
             WriterError::Other(e) => e.fmt(f)
         }
     }
@@ -16,13 +16,13 @@ impl fmt::Display for WriterError {
 
 impl fmt::Debug for WriterError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", &self)
+        write!(f, "{}", self)
     }
 }
 
 impl Error for WriterError {
     fn cause(&self) -> Option<&dyn Error> {
-        return match self {
+        match self {
             WriterError::Other(b) => Some(b.as_ref()),
         }
     }
@@ -36,4 +36,14 @@ impl std::convert::From<serde_json::Error> for WriterError {
 
 pub trait Writer<R> {
     fn write_cloud_event(res: HttpEvent) -> Result<R, WriterError>;
+
+    /// Like `write_cloud_event`, but encoding structured and batch mode events in the provided format.
+    ///
+    /// Writers supporting only the JSON format fail with other formats.
+    fn write_cloud_event_with_format(res: HttpEvent, format: EventFormat) -> Result<R, WriterError> {
+        if format != EventFormat::Json {
+            return Err(WriterError::Other(format!("Unsupported event format {:?}", format).into()));
+        }
+        Self::write_cloud_event(res)
+    }
 }
@@ -0,0 +1,11 @@
+mod record;
+
+pub use record::{attribute_name, header_name, read_event, MessageRecord};
+
+/// Prefix of the record headers carrying context attributes in binary mode
+pub const CE_KAFKA_HEADER_PREFIX: &str = "ce_";
+
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+
+/// Extension whose value is used as record key
+pub const PARTITION_KEY_EXTENSION: &str = "partitionkey";
@@ -0,0 +1,149 @@
+use super::{CE_KAFKA_HEADER_PREFIX, CONTENT_TYPE_HEADER, PARTITION_KEY_EXTENSION};
+use crate::binding;
+use crate::http::{ReaderError, WriterError, CE_JSON_CONTENT_TYPE};
+use crate::{Event, ExtensionValue};
+use rdkafka::message::{Header, Headers, Message, OwnedHeaders};
+use rdkafka::producer::FutureRecord;
+use std::str;
+
+/// Headers, key and value of a Kafka record encoding an event, to be sent with a producer.
+///
+/// The value of the `partitionkey` extension, when present, is used as record key.
+pub struct MessageRecord {
+    pub headers: OwnedHeaders,
+    pub key: Option<Vec<u8>>,
+    pub payload: Option<Vec<u8>>,
+}
+
+impl MessageRecord {
+    /// Encodes the event in binary mode: attributes in `ce_` prefixed headers,
+    /// `datacontenttype` in the `content-type` header and data as record value
+    pub fn from_binary_event(event: Event) -> Result<Self, WriterError> {
+        let mut headers = OwnedHeaders::new();
+        for (name, value) in binding::attributes(&event) {
+            headers = headers.insert(Header {
+                key: &header_name(&name),
+                value: Some(&value),
+            });
+        }
+        if let Some(p) = &event.payload {
+            headers = headers.insert(Header {
+                key: CONTENT_TYPE_HEADER,
+                value: Some(&p.content_type),
+            });
+        }
+
+        Ok(MessageRecord {
+            headers,
+            key: partition_key(&event),
+            payload: event.payload.map(|p| p.data),
+        })
+    }
+
+    /// Encodes the event in structured mode, as `application/cloudevents+json` record value
+    pub fn from_structured_event(event: Event) -> Result<Self, WriterError> {
+        let headers = OwnedHeaders::new().insert(Header {
+            key: CONTENT_TYPE_HEADER,
+            value: Some(CE_JSON_CONTENT_TYPE),
+        });
+
+        Ok(MessageRecord {
+            headers,
+            key: partition_key(&event),
+            payload: Some(serde_json::to_vec(&event)?),
+        })
+    }
+
+    /// Creates a [`FutureRecord`] for the topic, borrowing key and value from this record
+    pub fn to_future_record<'a>(&'a self, topic: &'a str) -> FutureRecord<'a, Vec<u8>, Vec<u8>> {
+        let mut record = FutureRecord::to(topic).headers(self.headers.clone());
+        if let Some(key) = &self.key {
+            record = record.key(key);
+        }
+        if let Some(payload) = &self.payload {
+            record = record.payload(payload);
+        }
+        record
+    }
+}
+
+/// Returns the record header name of the attribute, e.g. `ce_id` for `id`
+pub fn header_name(attribute_name: &str) -> String {
+    format!("{}{}", CE_KAFKA_HEADER_PREFIX, attribute_name)
+}
+
+/// Returns the attribute name carried by a `ce_` prefixed record header,
+/// or `None` if the header doesn't carry an attribute
+pub fn attribute_name(header_name: &str) -> Option<String> {
+    let header_name = header_name.to_lowercase();
+    header_name
+        .strip_prefix(CE_KAFKA_HEADER_PREFIX)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+}
+
+/// Reads the event encoded in the record, either in binary or structured mode.
+///
+/// The record key, when valid UTF-8, populates the `partitionkey` extension if the event doesn't carry it.
+/// Returns `None` if the record doesn't carry an event.
+pub fn read_event<M: Message>(message: &M) -> Result<Option<Event>, ReaderError> {
+    let event = read_record(message)?;
+    Ok(event.map(|mut event| {
+        if let Some(key) = message.key().and_then(|k| str::from_utf8(k).ok()) {
+            event
+                .extensions
+                .entry(PARTITION_KEY_EXTENSION.to_string())
+                .or_insert_with(|| ExtensionValue::from(key));
+        }
+        event
+    }))
+}
+
+fn read_record<M: Message>(message: &M) -> Result<Option<Event>, ReaderError> {
+    let mut content_type = None;
+    let mut attributes = Vec::new();
+    if let Some(headers) = message.headers() {
+        // Other headers are left undecoded, since they may carry binary values
+        for header in headers.iter() {
+            if header.key.eq_ignore_ascii_case(CONTENT_TYPE_HEADER) {
+                content_type = Some(header_value(&header, "datacontenttype")?);
+            } else if let Some(name) = attribute_name(header.key) {
+                let value = header_value(&header, &name)?;
+                attributes.push((name, value));
+            }
+        }
+    }
+
+    if let Some(ct) = &content_type {
+        if ct.starts_with(CE_JSON_CONTENT_TYPE) {
+            let payload = message.payload().ok_or_else(|| ReaderError::InvalidEncoding {
+                content_type: ct.clone(),
+                reason: "No value but content type is not null".to_string(),
+            })?;
+            return Ok(Some(serde_json::from_slice(payload)?));
+        }
+    }
+
+    if !attributes.iter().any(|(name, _)| name == "id") {
+        return Ok(None);
+    }
+
+    let payload = binding::payload(content_type, message.payload().map(<[u8]>::to_vec))?;
+    binding::event_from_attributes(attributes, payload).map(Some)
+}
+
+fn header_value(header: &Header<&[u8]>, name: &str) -> Result<String, ReaderError> {
+    str::from_utf8(header.value.unwrap_or_default())
+        .map(String::from)
+        .map_err(|e| ReaderError::InvalidMetadata {
+            name: name.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+fn partition_key(event: &Event) -> Option<Vec<u8>> {
+    event
+        .extensions
+        .get(PARTITION_KEY_EXTENSION)
+        .map(|v| v.to_string().into_bytes())
+}
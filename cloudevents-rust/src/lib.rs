@@ -2,13 +2,30 @@ extern crate chrono;
 extern crate hostname;
 extern crate serde;
 extern crate serde_json;
+extern crate url;
 extern crate uuid;
 
 #[macro_use]
 extern crate derive_builder;
 
 pub mod http;
+pub mod sse;
+pub mod websocket;
+#[cfg(feature = "kafka")]
+pub mod kafka;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(feature = "amqp")]
+pub mod amqp;
+#[cfg(feature = "nats")]
+pub mod nats;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(any(feature = "kafka", feature = "mqtt", feature = "amqp", feature = "nats", feature = "protobuf"))]
+mod binding;
 mod event;
 
 // Re-export only event stuff
-pub use event::{SpecVersion, Payload, Event, PayloadWriter, PayloadReader, PayloadResult, PayloadMapper};
+pub use event::{SpecVersion, Payload, Event, EventBuilder, ExtensionValue, StrictEvent, ValidationError, PayloadWriter, PayloadReader, PayloadResult, PayloadMapper};
@@ -0,0 +1,7 @@
+//! MQTT protocol binding for the [rumqttc](https://docs.rs/rumqttc) PUBLISH packets.
+//!
+//! MQTT 5 supports both binary and structured mode, while MQTT 3.1.1 supports only structured mode,
+//! since its packets can't carry metadata besides the payload.
+
+pub mod v3;
+pub mod v5;
@@ -0,0 +1,13 @@
+use crate::http::{ReaderError, WriterError};
+use crate::Event;
+use rumqttc::{Publish, QoS};
+
+/// Encodes the event in structured mode, as JSON payload
+pub fn write_structured(event: Event, topic: &str, qos: QoS) -> Result<Publish, WriterError> {
+    Ok(Publish::new(topic, qos, serde_json::to_vec(&event)?))
+}
+
+/// Reads the event encoded in structured mode in the packet payload
+pub fn read_event(publish: &Publish) -> Result<Event, ReaderError> {
+    Ok(serde_json::from_slice(&publish.payload)?)
+}
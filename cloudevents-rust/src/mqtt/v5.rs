@@ -0,0 +1,51 @@
+use crate::binding;
+use crate::http::{ReaderError, WriterError, CE_JSON_CONTENT_TYPE};
+use crate::Event;
+use rumqttc::v5::mqttbytes::v5::{Publish, PublishProperties};
+use rumqttc::v5::mqttbytes::QoS;
+
+/// Encodes the event in binary mode: attributes in user properties,
+/// `datacontenttype` in the content type property and data as payload
+pub fn write_binary(event: Event, topic: &str, qos: QoS) -> Result<Publish, WriterError> {
+    let properties = PublishProperties {
+        user_properties: binding::attributes(&event),
+        content_type: event.payload.as_ref().map(|p| p.content_type.clone()),
+        ..Default::default()
+    };
+    let payload = event.payload.map(|p| p.data).unwrap_or_default();
+
+    Ok(Publish::new(topic, qos, payload, Some(properties)))
+}
+
+/// Encodes the event in structured mode, as `application/cloudevents+json` payload
+pub fn write_structured(event: Event, topic: &str, qos: QoS) -> Result<Publish, WriterError> {
+    let properties = PublishProperties {
+        content_type: Some(CE_JSON_CONTENT_TYPE.to_string()),
+        ..Default::default()
+    };
+
+    Ok(Publish::new(topic, qos, serde_json::to_vec(&event)?, Some(properties)))
+}
+
+/// Reads the event encoded in the packet, either in binary or structured mode.
+///
+/// Returns `None` if the packet doesn't carry an event.
+pub fn read_event(publish: &Publish) -> Result<Option<Event>, ReaderError> {
+    let properties = match &publish.properties {
+        Some(p) => p,
+        None => return Ok(None),
+    };
+
+    if let Some(ct) = &properties.content_type {
+        if ct.starts_with(CE_JSON_CONTENT_TYPE) {
+            return Ok(Some(serde_json::from_slice(&publish.payload)?));
+        }
+    }
+
+    if !properties.user_properties.iter().any(|(name, _)| name == "specversion") {
+        return Ok(None);
+    }
+
+    let payload = binding::payload(properties.content_type.clone(), Some(publish.payload.to_vec()))?;
+    binding::event_from_attributes(properties.user_properties.iter().cloned(), payload).map(Some)
+}
@@ -0,0 +1,5 @@
+mod record;
+
+pub use record::{read_event, MessageRecord};
+
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
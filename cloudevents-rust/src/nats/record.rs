@@ -0,0 +1,78 @@
+use super::CONTENT_TYPE_HEADER;
+use crate::binding;
+use crate::http::{attribute_name, decode_header_value, encode_header_value, header_name, ReaderError, WriterError};
+use crate::Event;
+use async_nats::{HeaderMap, Message};
+
+/// Headers and payload of a NATS message encoding an event, to be sent with `Client::publish_with_headers`,
+/// or `Client::publish` when there are no headers
+pub struct MessageRecord {
+    pub headers: Option<HeaderMap>,
+    pub payload: Vec<u8>,
+}
+
+impl MessageRecord {
+    /// Encodes the event in binary mode: attributes in `ce-` prefixed headers,
+    /// `datacontenttype` in the `content-type` header and data as payload.
+    ///
+    /// Requires a server supporting headers.
+    pub fn from_binary_event(event: Event) -> Result<Self, WriterError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in binding::attributes(&event) {
+            headers.insert(header_name(&name).as_str(), encode_header_value(&value));
+        }
+        if let Some(p) = &event.payload {
+            headers.insert(CONTENT_TYPE_HEADER, p.content_type.as_str());
+        }
+
+        Ok(MessageRecord {
+            headers: Some(headers),
+            payload: event.payload.map(|p| p.data).unwrap_or_default(),
+        })
+    }
+
+    /// Encodes the event in structured mode, as JSON payload without headers
+    pub fn from_structured_event(event: Event) -> Result<Self, WriterError> {
+        Ok(MessageRecord {
+            headers: None,
+            payload: serde_json::to_vec(&event)?,
+        })
+    }
+}
+
+/// Reads the event encoded in the message, in binary mode if it has `ce-` prefixed headers,
+/// otherwise in structured mode.
+///
+/// Returns `None` if the message has neither `ce-` prefixed headers nor payload.
+pub fn read_event(message: &Message) -> Result<Option<Event>, ReaderError> {
+    let mut content_type = None;
+    let mut attributes = Vec::new();
+    if let Some(headers) = &message.headers {
+        for (name, values) in headers.iter() {
+            let name: &str = name.as_ref();
+            let value = match values.first() {
+                Some(v) => v.as_str(),
+                None => continue,
+            };
+            if name.eq_ignore_ascii_case(CONTENT_TYPE_HEADER) {
+                content_type = Some(value.to_string());
+            } else if let Some(name) = attribute_name(name) {
+                let value = decode_header_value(value.as_bytes()).map_err(|e| ReaderError::InvalidMetadata {
+                    name: name.clone(),
+                    reason: e.to_string(),
+                })?;
+                attributes.push((name, value));
+            }
+        }
+    }
+
+    if !attributes.iter().any(|(name, _)| name == "specversion") {
+        if message.payload.is_empty() {
+            return Ok(None);
+        }
+        return Ok(Some(serde_json::from_slice(&message.payload)?));
+    }
+
+    let payload = binding::payload(content_type, Some(message.payload.to_vec()))?;
+    binding::event_from_attributes(attributes, payload).map(Some)
+}
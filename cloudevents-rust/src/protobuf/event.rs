@@ -0,0 +1,174 @@
+use super::cloud_event::cloud_event_attribute_value::Attr;
+use super::cloud_event::{CloudEventAttributeValue, Data};
+use super::{CloudEvent, PROTOBUF_CONTENT_TYPE};
+use crate::binding;
+use crate::http::ReaderError;
+use crate::{Event, ExtensionValue};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use prost_types::{Any, Timestamp};
+use std::convert::TryFrom;
+use url::Url;
+
+const DATACONTENTTYPE_ATTRIBUTE: &str = "datacontenttype";
+
+/// Encodes the event with typed attribute values. The data is carried as:
+///
+/// * `proto_data` if the content type is protobuf and the event has a `dataschema`, used as type URL
+/// * `text_data` if the content type is textual, like `text/*`, JSON or XML
+/// * `binary_data` otherwise
+impl From<Event> for CloudEvent {
+    fn from(event: Event) -> Self {
+        let mut attributes = binding::typed_attributes(&event)
+            .into_iter()
+            .filter(|(name, _)| !matches!(name.as_str(), "specversion" | "id" | "source" | "type"))
+            .map(|(name, value)| (name, attribute_value(value)))
+            .collect::<std::collections::HashMap<_, _>>();
+
+        let data = match event.payload {
+            Some(p) => {
+                let data = encode_data(&p.content_type, p.data, event.data_schema.as_ref());
+                attributes.insert(
+                    DATACONTENTTYPE_ATTRIBUTE.to_string(),
+                    attribute_value(ExtensionValue::String(p.content_type)),
+                );
+                Some(data)
+            }
+            None => None,
+        };
+
+        CloudEvent {
+            id: event.id,
+            source: event.source,
+            spec_version: event.spec_version.to_string(),
+            r#type: event.event_type,
+            attributes,
+            data,
+        }
+    }
+}
+
+/// Decodes the event, keeping the type of the extension values.
+///
+/// When the data is `proto_data` and the event has no `dataschema`, the type URL is used as `dataschema`.
+impl TryFrom<CloudEvent> for Event {
+    type Error = ReaderError;
+
+    fn try_from(message: CloudEvent) -> Result<Self, ReaderError> {
+        let mut attributes = vec![
+            ("specversion".to_string(), ExtensionValue::from(message.spec_version)),
+            ("id".to_string(), ExtensionValue::from(message.id)),
+            ("source".to_string(), ExtensionValue::from(message.source)),
+            ("type".to_string(), ExtensionValue::from(message.r#type)),
+        ];
+        let mut content_type = None;
+        for (name, value) in message.attributes {
+            let value = extension_value(&name, value)?;
+            if name == DATACONTENTTYPE_ATTRIBUTE {
+                content_type = Some(value.to_string());
+            } else {
+                attributes.push((name, value));
+            }
+        }
+
+        let (payload, type_url) = match message.data {
+            Some(Data::BinaryData(data)) => (
+                Some((content_type.unwrap_or_else(|| "application/octet-stream".to_string()), data)),
+                None,
+            ),
+            Some(Data::TextData(text)) => (
+                Some((content_type.unwrap_or_else(|| "text/plain".to_string()), text.into_bytes())),
+                None,
+            ),
+            Some(Data::ProtoData(any)) => (
+                Some((content_type.unwrap_or_else(|| PROTOBUF_CONTENT_TYPE.to_string()), any.value)),
+                Some(any.type_url),
+            ),
+            None => (None, None),
+        };
+
+        let mut event = binding::event_from_attributes(attributes, payload)?;
+        if let Some(type_url) = type_url {
+            if event.data_schema.is_none() {
+                event.data_schema = Some(type_url_to_url(&type_url)?);
+            }
+        }
+        Ok(event)
+    }
+}
+
+fn encode_data(content_type: &str, data: Vec<u8>, data_schema: Option<&Url>) -> Data {
+    let media_type = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    match data_schema {
+        Some(ds) if media_type == PROTOBUF_CONTENT_TYPE || media_type == "application/x-protobuf" => {
+            return Data::ProtoData(Any {
+                type_url: ds.to_string(),
+                value: data,
+            })
+        }
+        _ => {}
+    }
+
+    let textual = media_type.starts_with("text/")
+        || media_type == "application/json"
+        || media_type == "application/xml"
+        || media_type.ends_with("+json")
+        || media_type.ends_with("+xml");
+    if !textual {
+        return Data::BinaryData(data);
+    }
+    match String::from_utf8(data) {
+        Ok(text) => Data::TextData(text),
+        Err(e) => Data::BinaryData(e.into_bytes()),
+    }
+}
+
+// Type URLs without scheme, like type.googleapis.com/..., are https URLs as defined by google.protobuf.Any
+fn type_url_to_url(type_url: &str) -> Result<Url, ReaderError> {
+    Url::parse(type_url)
+        .or_else(|_| Url::parse(&format!("https://{}", type_url)))
+        .map_err(|e| invalid_metadata("dataschema", e))
+}
+
+fn attribute_value(value: ExtensionValue) -> CloudEventAttributeValue {
+    let attr = match value {
+        ExtensionValue::Boolean(b) => Attr::CeBoolean(b),
+        ExtensionValue::Integer(i) => Attr::CeInteger(i),
+        ExtensionValue::String(s) => Attr::CeString(s),
+        ExtensionValue::Binary(b) => Attr::CeBytes(b),
+        ExtensionValue::Uri(u) => Attr::CeUri(u.to_string()),
+        ExtensionValue::UriRef(u) => Attr::CeUriRef(u),
+        ExtensionValue::Timestamp(t) => Attr::CeTimestamp(Timestamp {
+            seconds: t.timestamp(),
+            nanos: t.timestamp_subsec_nanos() as i32,
+        }),
+    };
+    CloudEventAttributeValue { attr: Some(attr) }
+}
+
+fn extension_value(name: &str, value: CloudEventAttributeValue) -> Result<ExtensionValue, ReaderError> {
+    Ok(match value.attr {
+        Some(Attr::CeBoolean(b)) => ExtensionValue::Boolean(b),
+        Some(Attr::CeInteger(i)) => ExtensionValue::Integer(i),
+        Some(Attr::CeString(s)) => ExtensionValue::String(s),
+        Some(Attr::CeBytes(b)) => ExtensionValue::Binary(b),
+        Some(Attr::CeUri(u)) => ExtensionValue::Uri(Url::parse(&u).map_err(|e| invalid_metadata(name, e))?),
+        Some(Attr::CeUriRef(u)) => ExtensionValue::UriRef(u),
+        Some(Attr::CeTimestamp(t)) => ExtensionValue::Timestamp(timestamp(name, &t)?),
+        None => return Err(invalid_metadata(name, "Missing attribute value")),
+    })
+}
+
+fn timestamp(name: &str, t: &Timestamp) -> Result<DateTime<FixedOffset>, ReaderError> {
+    u32::try_from(t.nanos)
+        .ok()
+        .and_then(|nanos| Utc.timestamp_opt(t.seconds, nanos).single())
+        .map(Into::into)
+        .ok_or_else(|| invalid_metadata(name, format!("Invalid timestamp {:?}", t)))
+}
+
+fn invalid_metadata(name: &str, reason: impl ToString) -> ReaderError {
+    ReaderError::InvalidMetadata {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    }
+}
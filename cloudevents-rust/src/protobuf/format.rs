@@ -0,0 +1,32 @@
+use super::{CloudEvent, CloudEventBatch};
+use crate::http::ReaderError;
+use crate::Event;
+use prost::Message;
+use std::convert::TryFrom;
+
+/// Encodes the event as `application/cloudevents+protobuf`
+pub fn to_vec(event: Event) -> Vec<u8> {
+    CloudEvent::from(event).encode_to_vec()
+}
+
+/// Decodes an `application/cloudevents+protobuf` event
+pub fn from_slice(data: &[u8]) -> Result<Event, ReaderError> {
+    Event::try_from(CloudEvent::decode(data)?)
+}
+
+/// Encodes the events as `application/cloudevents-batch+protobuf`
+pub fn batch_to_vec(events: Vec<Event>) -> Vec<u8> {
+    CloudEventBatch {
+        events: events.into_iter().map(CloudEvent::from).collect(),
+    }
+    .encode_to_vec()
+}
+
+/// Decodes an `application/cloudevents-batch+protobuf` batch of events
+pub fn batch_from_slice(data: &[u8]) -> Result<Vec<Event>, ReaderError> {
+    CloudEventBatch::decode(data)?
+        .events
+        .into_iter()
+        .map(Event::try_from)
+        .collect()
+}
@@ -0,0 +1,15 @@
+//! [Protobuf format](https://github.com/cloudevents/spec/blob/main/cloudevents/formats/protobuf-format.md) of events.
+//!
+//! The messages are generated with `tonic-prost-build` from `proto/cloudevents.proto`,
+//! running `cargo run --manifest-path codegen/Cargo.toml` from the repository root,
+//! while `Event` converts from and to [`CloudEvent`].
+
+mod generated;
+mod event;
+mod format;
+
+pub use format::{batch_from_slice, batch_to_vec, from_slice, to_vec};
+pub use generated::{cloud_event, CloudEvent, CloudEventBatch};
+
+/// Content type of the data carried as `proto_data`, together with the type URL as `dataschema`
+pub const PROTOBUF_CONTENT_TYPE: &str = "application/protobuf";
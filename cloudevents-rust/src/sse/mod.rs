@@ -0,0 +1,10 @@
+//! [Server-Sent Events](https://html.spec.whatwg.org/multipage/server-sent-events.html) encoding of events:
+//! the event `id` as SSE `id` field, the event `type` as SSE `event` field and the structured JSON as data
+
+mod parser;
+mod writer;
+
+pub use parser::SseParser;
+pub use writer::write_event;
+
+pub const SSE_CONTENT_TYPE: &str = "text/event-stream";
@@ -0,0 +1,67 @@
+use crate::http::ReaderError;
+use crate::Event;
+
+/// Incremental parser of a SSE byte stream, decoding the structured JSON events in the `data` fields.
+///
+/// Lines can be terminated by `\n`, `\r\n` or a bare `\r`, even when split across chunks. Fields other than `data` and comments are ignored,
+/// since the structured JSON carries all the attributes.
+#[derive(Default)]
+pub struct SseParser {
+    line: Vec<u8>,
+    data: Option<String>,
+    // Whether the last line was terminated by `\r`, so that a following `\n` completes the `\r\n` terminator
+    after_cr: bool,
+}
+
+impl SseParser {
+    pub fn new() -> SseParser {
+        SseParser::default()
+    }
+
+    /// Feeds the next chunk of the stream, returning the events completed by it
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<Result<Event, ReaderError>> {
+        let mut events = Vec::new();
+        for &b in chunk {
+            let after_cr = std::mem::replace(&mut self.after_cr, b == b'\r');
+            if b == b'\n' && after_cr {
+                continue;
+            }
+            if b != b'\n' && b != b'\r' {
+                self.line.push(b);
+                continue;
+            }
+            let line = std::mem::take(&mut self.line);
+            if let Some(event) = self.process_line(&line) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    fn process_line(&mut self, line: &[u8]) -> Option<Result<Event, ReaderError>> {
+        // A blank line dispatches the event
+        if line.is_empty() {
+            return self
+                .data
+                .take()
+                .map(|data| serde_json::from_str(&data).map_err(ReaderError::from));
+        }
+
+        let line = String::from_utf8_lossy(line);
+        let (field, value) = match line.find(':') {
+            Some(0) => return None,
+            Some(i) => (&line[..i], line[i + 1..].strip_prefix(' ').unwrap_or(&line[i + 1..])),
+            None => (line.as_ref(), ""),
+        };
+        if field == "data" {
+            match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            }
+        }
+        None
+    }
+}
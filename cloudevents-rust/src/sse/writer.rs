@@ -0,0 +1,20 @@
+use crate::http::WriterError;
+use crate::Event;
+
+/// Encodes the event as SSE message, terminated by a blank line
+pub fn write_event(event: &Event) -> Result<String, WriterError> {
+    let mut message = String::new();
+    // Fields are line delimited, values with line breaks can't be written
+    if !has_line_break(&event.id) {
+        message.push_str(&format!("id: {}\n", event.id));
+    }
+    if !has_line_break(&event.event_type) {
+        message.push_str(&format!("event: {}\n", event.event_type));
+    }
+    message.push_str(&format!("data: {}\n\n", serde_json::to_string(event)?));
+    Ok(message)
+}
+
+fn has_line_break(value: &str) -> bool {
+    value.contains(['\n', '\r'])
+}
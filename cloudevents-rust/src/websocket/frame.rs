@@ -0,0 +1,78 @@
+use super::CE_JSON_SUBPROTOCOL;
+#[cfg(feature = "protobuf")]
+use super::CE_PROTOBUF_SUBPROTOCOL;
+use crate::http::{ReaderError, WriterError};
+use crate::Event;
+
+/// Subprotocol negotiated with the `Sec-WebSocket-Protocol` header, defining the format of the events in the frames
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Subprotocol {
+    Json,
+    /// Enabling the `protobuf` feature
+    #[cfg(feature = "protobuf")]
+    Protobuf,
+}
+
+impl Subprotocol {
+    /// Subprotocols supported by this crate, in order of preference
+    pub const SUPPORTED: &'static [Subprotocol] = &[
+        Subprotocol::Json,
+        #[cfg(feature = "protobuf")]
+        Subprotocol::Protobuf,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Subprotocol::Json => CE_JSON_SUBPROTOCOL,
+            #[cfg(feature = "protobuf")]
+            Subprotocol::Protobuf => CE_PROTOBUF_SUBPROTOCOL,
+        }
+    }
+
+    /// Returns the first supported subprotocol between the comma separated ones offered by the client
+    /// in the `Sec-WebSocket-Protocol` header, or `None` if no offered subprotocol is supported
+    pub fn negotiate(offered: &str) -> Option<Subprotocol> {
+        offered
+            .split(',')
+            .map(str::trim)
+            .find_map(|name| Subprotocol::SUPPORTED.iter().find(|s| s.name() == name))
+            .copied()
+    }
+}
+
+/// Data frame carrying a single event
+#[derive(PartialEq, Debug, Clone)]
+pub enum Frame {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Encodes the event in a frame, text for the JSON format and binary for the Protobuf format
+pub fn write_event(event: &Event, subprotocol: Subprotocol) -> Result<Frame, WriterError> {
+    match subprotocol {
+        Subprotocol::Json => Ok(Frame::Text(serde_json::to_string(event)?)),
+        #[cfg(feature = "protobuf")]
+        Subprotocol::Protobuf => Ok(Frame::Binary(crate::protobuf::to_vec(event.clone()))),
+    }
+}
+
+/// Decodes the event carried by the frame.
+///
+/// JSON events are read from both text and binary frames, while Protobuf events only from binary frames.
+pub fn read_event(frame: &Frame, subprotocol: Subprotocol) -> Result<Event, ReaderError> {
+    match subprotocol {
+        Subprotocol::Json => Ok(match frame {
+            Frame::Text(text) => serde_json::from_str(text)?,
+            Frame::Binary(data) => serde_json::from_slice(data)?,
+        }),
+        #[cfg(feature = "protobuf")]
+        Subprotocol::Protobuf => match frame {
+            Frame::Binary(data) => crate::protobuf::from_slice(data),
+            Frame::Text(_) => Err(ReaderError::InvalidEncoding {
+                content_type: CE_PROTOBUF_SUBPROTOCOL.to_string(),
+                reason: "Expecting a binary frame".to_string(),
+            }),
+        },
+    }
+}
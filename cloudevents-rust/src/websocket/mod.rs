@@ -0,0 +1,10 @@
+mod frame;
+
+pub use frame::{read_event, write_event, Frame, Subprotocol};
+
+/// Subprotocol for events encoded with the JSON format
+pub const CE_JSON_SUBPROTOCOL: &str = "cloudevents.json";
+
+/// Subprotocol for events encoded with the [`protobuf`](crate::protobuf) format
+#[cfg(feature = "protobuf")]
+pub const CE_PROTOBUF_SUBPROTOCOL: &str = "cloudevents.proto";
@@ -0,0 +1,169 @@
+#![cfg(feature = "amqp")]
+
+mod common;
+
+use chrono::DateTime;
+use cloudevents::amqp::{read_event, write_binary, write_structured, AmqpMessage};
+use cloudevents::http::ReaderError;
+use cloudevents::{Event, ExtensionValue};
+use common::full_event;
+use fe2o3_amqp_types::messaging::message::DecodeIntoMessage;
+use fe2o3_amqp_types::messaging::{ApplicationProperties, Body};
+use fe2o3_amqp_types::primitives::{OrderedMap, SimpleValue, Timestamp, Value};
+use serde::Serialize;
+use serde_amqp::read::SliceReader;
+
+// Event with extensions of each type, written as typed application properties
+fn event() -> Event {
+    let mut event = full_event();
+    event.extensions.insert("comexampleextension2".to_string(), ExtensionValue::from(10));
+    event.extensions.insert("comexampleextension3".to_string(), ExtensionValue::from(true));
+    event
+}
+
+// Encodes and decodes the message, as it would be sent over the wire.
+// The encoded message is the sequence of its encoded sections.
+fn transfer(message: AmqpMessage) -> AmqpMessage {
+    fn section<T: Serialize>(encoded: &mut Vec<u8>, section: &Option<T>) {
+        if let Some(section) = section {
+            encoded.extend(serde_amqp::to_vec(section).unwrap());
+        }
+    }
+
+    let mut encoded = Vec::new();
+    section(&mut encoded, &message.header);
+    section(&mut encoded, &message.delivery_annotations);
+    section(&mut encoded, &message.message_annotations);
+    section(&mut encoded, &message.properties);
+    section(&mut encoded, &message.application_properties);
+    encoded.extend(serde_amqp::to_vec(&message.body).unwrap());
+    section(&mut encoded, &message.footer);
+    Body::<Value>::decode_message_from_reader(SliceReader::new(&encoded)).unwrap()
+}
+
+fn property(message: &AmqpMessage, name: &str) -> Option<SimpleValue> {
+    message.application_properties.as_ref().unwrap().0.get(name).cloned()
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let message = transfer(write_binary(event()).unwrap());
+
+    assert_eq!(
+        property(&message, "cloudEvents:id"),
+        Some(SimpleValue::String("A234-1234-1234".to_string()))
+    );
+    assert_eq!(
+        property(&message, "cloudEvents:time"),
+        Some(SimpleValue::Timestamp(Timestamp::from_milliseconds(1522949460000)))
+    );
+    assert_eq!(property(&message, "cloudEvents:comexampleextension2"), Some(SimpleValue::Int(10)));
+    assert_eq!(property(&message, "cloudEvents:comexampleextension3"), Some(SimpleValue::Bool(true)));
+    assert_eq!(
+        message.properties.as_ref().unwrap().content_type.as_deref().map(String::as_str),
+        Some("application/xml")
+    );
+    assert_eq!(read_event(&message).unwrap(), Some(event()));
+}
+
+#[test]
+fn test_structured_round_trip() {
+    let message = transfer(write_structured(event()).unwrap());
+
+    assert_eq!(
+        message.properties.as_ref().unwrap().content_type.as_deref().map(String::as_str),
+        Some("application/cloudevents+json")
+    );
+    assert!(message.application_properties.is_none());
+    assert_eq!(read_event(&message).unwrap(), Some(event()));
+}
+
+#[test]
+fn test_binary_writes_colon_prefix() {
+    let message = write_binary(event()).unwrap();
+
+    // The `cloudEvents_` prefix is only read, for JMS interoperability
+    assert!(message
+        .application_properties
+        .unwrap()
+        .0
+        .keys()
+        .all(|name| name.starts_with("cloudEvents:")));
+}
+
+#[test]
+fn test_read_jms_property_names() {
+    let mut properties = OrderedMap::new();
+    properties.insert("cloudEvents_specversion".to_string(), SimpleValue::from("1.0"));
+    properties.insert("cloudEvents_id".to_string(), SimpleValue::from("1"));
+    properties.insert("cloudEvents_source".to_string(), SimpleValue::from("/source"));
+    properties.insert("cloudEvents_type".to_string(), SimpleValue::from("t"));
+    let mut message = write_structured(event()).unwrap();
+    message.properties = None;
+    message.body = Body::Empty;
+    message.application_properties = Some(ApplicationProperties(properties));
+
+    let event = read_event(&message).unwrap().unwrap();
+
+    assert_eq!(event.id, "1");
+    assert_eq!(event.payload, None);
+}
+
+#[test]
+fn test_read_binary_without_content_type() {
+    let mut message = write_binary(event()).unwrap();
+    message.properties = None;
+
+    match read_event(&message) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "datacontenttype"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
+
+#[test]
+fn test_read_mixed_prefixes() {
+    let mut properties = OrderedMap::new();
+    properties.insert("cloudEvents:specversion".to_string(), SimpleValue::from("1.0"));
+    properties.insert("cloudEvents_id".to_string(), SimpleValue::from("1"));
+    properties.insert("cloudEvents:source".to_string(), SimpleValue::from("/source"));
+    properties.insert("cloudEvents_type".to_string(), SimpleValue::from("t"));
+    properties.insert("cloudEvents_comexampleextension1".to_string(), SimpleValue::from("value"));
+    properties.insert("cloudEvents-other".to_string(), SimpleValue::from("ignored"));
+    properties.insert("other".to_string(), SimpleValue::from("ignored"));
+    let mut message = write_structured(event()).unwrap();
+    message.properties = None;
+    message.body = Body::Empty;
+    message.application_properties = Some(ApplicationProperties(properties));
+
+    let event = read_event(&message).unwrap().unwrap();
+
+    assert_eq!(event.id, "1");
+    assert_eq!(event.event_type, "t");
+    assert_eq!(event.extensions.len(), 1);
+    assert_eq!(event.extensions.get("comexampleextension1"), Some(&ExtensionValue::from("value")));
+}
+
+#[test]
+fn test_read_typed_values() {
+    let binary = |name: &str, value: SimpleValue| {
+        let mut message = write_binary(event()).unwrap();
+        message.application_properties.as_mut().unwrap().0.insert(name.to_string(), value);
+        message
+    };
+    let with_long = binary("cloudEvents:comexampleextension2", SimpleValue::Long(20));
+    let with_timestamp = binary("cloudEvents:time", SimpleValue::Timestamp(Timestamp::from_milliseconds(0)));
+    let with_overflow = binary("cloudEvents:comexampleextension2", SimpleValue::Long(i64::MAX));
+
+    assert_eq!(
+        read_event(&with_long).unwrap().unwrap().extensions.get("comexampleextension2"),
+        Some(&ExtensionValue::from(20))
+    );
+    assert_eq!(
+        read_event(&with_timestamp).unwrap().unwrap().time,
+        Some(DateTime::parse_from_rfc3339("1970-01-01T00:00:00Z").unwrap())
+    );
+    match read_event(&with_overflow) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "comexampleextension2"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
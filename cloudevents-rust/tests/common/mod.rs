@@ -0,0 +1,39 @@
+//! Events shared by the integration tests
+#![allow(dead_code)]
+
+use chrono::DateTime;
+use cloudevents::{Event, EventBuilder, ExtensionValue, Payload};
+use url::Url;
+
+/// Event with the required attributes and a `text/plain` payload
+pub fn event() -> Event {
+    EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello".as_bytes().into(),
+        })
+        .build()
+        .unwrap()
+}
+
+/// Event with all the optional attributes, an extension and an `application/xml` payload
+pub fn full_event() -> Event {
+    let mut event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .subject("123")
+        .time(DateTime::parse_from_rfc3339("2018-04-05T17:31:00Z").unwrap())
+        .data_schema(Url::parse("https://example.com/schema").unwrap())
+        .payload(Payload {
+            content_type: "application/xml".to_string(),
+            data: "<much wow=\"xml\"/>".as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+    event.extensions.insert("comexampleextension1".to_string(), ExtensionValue::from("value"));
+    event
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "grpc")]
+
+mod common;
+
+use cloudevents::grpc::{read_event, CloudEventService, CloudEventServiceClient, CloudEventServiceServer, PublishRequest};
+use cloudevents::Event;
+use common::event;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::{Channel, Server};
+use tonic::{Code, Request, Response, Status};
+
+#[derive(Default)]
+struct Sink {
+    received: Arc<Mutex<Vec<Event>>>,
+}
+
+#[tonic::async_trait]
+impl CloudEventService for Sink {
+    async fn publish(&self, request: Request<PublishRequest>) -> Result<Response<()>, Status> {
+        let event = read_event(request.into_inner())?
+            .ok_or_else(|| Status::invalid_argument("No event in the request"))?;
+        self.received.lock().unwrap().push(event);
+        Ok(Response::new(()))
+    }
+}
+
+// Starts an in-process server, returning a client connected to it and the received events
+async fn start() -> (CloudEventServiceClient<Channel>, Arc<Mutex<Vec<Event>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let sink = Sink::default();
+    let received = sink.received.clone();
+
+    tokio::spawn(
+        Server::builder()
+            .add_service(CloudEventServiceServer::new(sink))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+
+    let channel = Channel::from_shared(format!("http://{}", addr))
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    (CloudEventServiceClient::new(channel), received)
+}
+
+#[tokio::test]
+async fn test_publish() {
+    let (mut client, received) = start().await;
+
+    client.publish(PublishRequest::from(event())).await.unwrap();
+
+    assert_eq!(*received.lock().unwrap(), vec![event()]);
+}
+
+#[tokio::test]
+async fn test_publish_without_event() {
+    let (mut client, received) = start().await;
+
+    let status = client.publish(PublishRequest { event: None }).await.unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_publish_invalid_event() {
+    let (mut client, received) = start().await;
+
+    let mut request = PublishRequest::from(event());
+    request.event.as_mut().unwrap().spec_version = "9.9".to_string();
+    let status = client.publish(request).await.unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(status.message(), "Invalid metadata 'specversion': Invalid specversion 9.9");
+    assert!(received.lock().unwrap().is_empty());
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "http")]
+
+use chrono::DateTime;
+#[cfg(feature = "protobuf")]
+use cloudevents::http::EventFormat;
+use cloudevents::http::{EventReader, EventWriter, HttpEvent, HttpReader, HttpWriter, ReaderError};
+use cloudevents::{Event, EventBuilder, ExtensionValue, Payload};
+use http::{HeaderMap, Method, Request, Response};
+
+fn full_event() -> Event {
+    let mut event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .subject("Ünïcödé subject")
+        .time(DateTime::parse_from_rfc3339("2018-04-05T17:31:00Z").unwrap())
+        .payload(Payload {
+            content_type: "application/json".to_string(),
+            data: r#"{"hello":"world"}"#.as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+    event.extensions.insert("traceparent".to_string(), ExtensionValue::from("abc"));
+    event
+}
+
+#[test]
+fn test_request_binary_round_trip() {
+    let event = full_event();
+
+    let req: Request<Vec<u8>> = HttpWriter::write_cloud_event(HttpEvent::Binary(event.clone())).unwrap();
+
+    assert_eq!(req.method(), Method::POST);
+    assert_eq!(req.headers().get("ce-id").unwrap(), "A234-1234-1234");
+    assert_eq!(req.headers().get("ce-traceparent").unwrap(), "abc");
+    assert_eq!(req.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(
+        HttpReader::read_cloud_event(req).unwrap(),
+        Some(HttpEvent::Binary(event))
+    );
+}
+
+#[test]
+fn test_response_structured_round_trip() {
+    let event = full_event();
+
+    let res: Response<Vec<u8>> = HttpWriter::write_cloud_event(HttpEvent::Structured(event.clone())).unwrap();
+
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        cloudevents::http::CE_JSON_CONTENT_TYPE
+    );
+    assert_eq!(
+        HttpReader::read_cloud_event(res).unwrap(),
+        Some(HttpEvent::Structured(event))
+    );
+}
+
+#[test]
+fn test_header_map_batch_round_trip() {
+    let events = vec![full_event(), full_event()];
+
+    let (headers, body): (HeaderMap, Vec<u8>) = HttpWriter::write_cloud_event(HttpEvent::Batch(events.clone())).unwrap();
+
+    assert_eq!(
+        HttpReader::read_cloud_event((headers, body)).unwrap(),
+        Some(HttpEvent::Batch(events))
+    );
+}
+
+#[cfg(feature = "protobuf")]
+#[test]
+fn test_response_structured_protobuf_round_trip() {
+    let event = full_event();
+
+    let res: Response<Vec<u8>> =
+        HttpWriter::write_cloud_event_with_format(HttpEvent::Structured(event.clone()), EventFormat::Protobuf).unwrap();
+    let content_type = res.headers().get("content-type").unwrap().to_str().unwrap().to_string();
+
+    assert_eq!(content_type, cloudevents::http::CE_PROTOBUF_CONTENT_TYPE);
+    assert_eq!(EventFormat::from_content_type(&content_type), Some(EventFormat::Protobuf));
+    assert_eq!(
+        HttpReader::read_cloud_event(res).unwrap(),
+        Some(HttpEvent::Structured(event))
+    );
+}
+
+#[cfg(feature = "protobuf")]
+#[test]
+fn test_header_map_batch_protobuf_round_trip() {
+    let events = vec![full_event(), full_event()];
+
+    let (headers, body): (HeaderMap, Vec<u8>) =
+        HttpWriter::write_cloud_event_with_format(HttpEvent::Batch(events.clone()), EventFormat::Protobuf).unwrap();
+
+    assert_eq!(
+        headers.get("content-type").unwrap(),
+        cloudevents::http::CE_BATCH_PROTOBUF_CONTENT_TYPE
+    );
+    assert_eq!(
+        HttpReader::read_cloud_event_with_format((headers, body)).unwrap(),
+        Some((HttpEvent::Batch(events), EventFormat::Protobuf))
+    );
+}
+
+#[test]
+fn test_read_no_event() {
+    let req = Request::new(Vec::<u8>::new());
+
+    assert_eq!(HttpReader::read_cloud_event(req).unwrap(), None);
+}
+
+#[test]
+fn test_read_missing_body() {
+    let req = Request::builder()
+        .header("content-type", "application/json")
+        .header("ce-id", "A234-1234-1234")
+        .body(Vec::<u8>::new())
+        .unwrap();
+
+    match HttpReader::read_cloud_event(req) {
+        Err(ReaderError::InvalidEncoding { content_type, .. }) => assert_eq!(content_type, "application/json"),
+        _ => panic!("Expecting invalid encoding error"),
+    }
+}
+
+fn binary_request(spec_version: &str, body: &str) -> Request<Vec<u8>> {
+    Request::builder()
+        .header("content-type", "text/plain")
+        .header("ce-specversion", spec_version)
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-datacontentencoding", "base64")
+        .body(body.as_bytes().to_vec())
+        .unwrap()
+}
+
+#[test]
+fn test_read_base64_encoded_body_v03() {
+    let event = HttpReader::read_cloud_event(binary_request("0.3", "aGVsbG8=")).unwrap().unwrap();
+
+    match event {
+        HttpEvent::Binary(event) => {
+            assert_eq!(event.payload.unwrap().data, b"hello");
+            assert!(event.extensions.is_empty());
+        }
+        e => panic!("Expecting binary event, found {:?}", e),
+    }
+}
+
+#[test]
+fn test_read_datacontentencoding_extension_v10() {
+    let event = HttpReader::read_cloud_event(binary_request("1.0", "aGVsbG8=")).unwrap().unwrap();
+
+    match event {
+        HttpEvent::Binary(event) => {
+            assert_eq!(event.payload.unwrap().data, b"aGVsbG8=");
+            assert_eq!(event.extensions.get("datacontentencoding"), Some(&ExtensionValue::from("base64")));
+        }
+        e => panic!("Expecting binary event, found {:?}", e),
+    }
+}
@@ -0,0 +1,45 @@
+use cloudevents::http;
+
+#[test]
+fn test_header_name() {
+    assert_eq!(http::header_name("traceparent"), "ce-traceparent");
+}
+
+#[test]
+fn test_attribute_name() {
+    assert_eq!(http::attribute_name("ce-traceparent"), Some("traceparent".to_string()));
+    assert_eq!(http::attribute_name("CE-TraceParent"), Some("traceparent".to_string()));
+    assert_eq!(http::attribute_name("ce-"), None);
+    assert_eq!(http::attribute_name("content-type"), None);
+}
+
+#[test]
+fn test_encode_header_value() {
+    assert_eq!(http::encode_header_value("Euro € 😀"), "Euro%20%E2%82%AC%20%F0%9F%98%80");
+    assert_eq!(http::encode_header_value(r#"100% "quoted""#), "100%25%20%22quoted%22");
+    assert_eq!(http::encode_header_value("/some/path?a=b&c=d#e"), "/some/path?a=b&c=d#e");
+}
+
+#[test]
+fn test_decode_header_value() {
+    assert_eq!(
+        http::decode_header_value(b"Euro%20%E2%82%AC%20%F0%9F%98%80"),
+        Ok("Euro € 😀".to_string())
+    );
+    assert_eq!(
+        http::decode_header_value(b"100%25%20%22quoted%22"),
+        Ok(r#"100% "quoted""#.to_string())
+    );
+    assert_eq!(http::decode_header_value("Euro €".as_bytes()), Ok("Euro €".to_string()));
+    assert!(http::decode_header_value(b"%FF").is_err());
+}
+
+#[test]
+fn test_encode_decode_header_value_round_trip() {
+    for value in ["hello world", "ünïcödé", "%%20", "\"'", "tab\tnew\nline"] {
+        assert_eq!(
+            http::decode_header_value(http::encode_header_value(value).as_bytes()),
+            Ok(value.to_string())
+        );
+    }
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "kafka")]
+
+mod common;
+
+use cloudevents::http::ReaderError;
+use cloudevents::kafka::{read_event, MessageRecord};
+use cloudevents::{Event, ExtensionValue};
+use common::full_event;
+use rdkafka::message::{Header, Headers, Message, OwnedHeaders, OwnedMessage, Timestamp};
+
+// Event with a partition key, written as the record key
+fn event() -> Event {
+    let mut event = full_event();
+    event.extensions.insert("partitionkey".to_string(), ExtensionValue::from("key-1"));
+    event
+}
+
+fn message(record: MessageRecord) -> OwnedMessage {
+    OwnedMessage::new(
+        record.payload,
+        record.key,
+        "events".to_string(),
+        Timestamp::NotAvailable,
+        0,
+        0,
+        Some(record.headers),
+    )
+}
+
+fn header(message: &OwnedMessage, key: &str) -> Option<String> {
+    message
+        .headers()
+        .unwrap()
+        .iter()
+        .find(|h| h.key == key)
+        .map(|h| String::from_utf8(h.value.unwrap().to_vec()).unwrap())
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let message = message(MessageRecord::from_binary_event(event()).unwrap());
+
+    assert_eq!(header(&message, "ce_id").unwrap(), "A234-1234-1234");
+    assert_eq!(header(&message, "ce_comexampleextension1").unwrap(), "value");
+    assert_eq!(header(&message, "content-type").unwrap(), "application/xml");
+    assert_eq!(message.payload().unwrap(), "<much wow=\"xml\"/>".as_bytes());
+    assert_eq!(read_event(&message).unwrap(), Some(event()));
+}
+
+#[test]
+fn test_structured_round_trip() {
+    let message = message(MessageRecord::from_structured_event(event()).unwrap());
+
+    assert_eq!(header(&message, "content-type").unwrap(), "application/cloudevents+json");
+    assert_eq!(header(&message, "ce_id"), None);
+    assert_eq!(read_event(&message).unwrap(), Some(event()));
+}
+
+#[test]
+fn test_partition_key_as_record_key() {
+    let binary = message(MessageRecord::from_binary_event(event()).unwrap());
+    let structured = message(MessageRecord::from_structured_event(event()).unwrap());
+
+    assert_eq!(binary.key().unwrap(), b"key-1");
+    assert_eq!(structured.key().unwrap(), b"key-1");
+
+    let mut without_key = event();
+    without_key.extensions.remove("partitionkey");
+    assert_eq!(MessageRecord::from_binary_event(without_key).unwrap().key, None);
+}
+
+#[test]
+fn test_binary_header_values_are_raw_utf8() {
+    let mut event = event();
+    event.extensions.insert("comexampleextension2".to_string(), ExtensionValue::from("välue with spaces"));
+
+    let message = message(MessageRecord::from_binary_event(event.clone()).unwrap());
+
+    // Unlike HTTP headers, Kafka header values are bytes and aren't percent-encoded
+    assert_eq!(header(&message, "ce_comexampleextension2").unwrap(), "välue with spaces");
+    assert_eq!(read_event(&message).unwrap(), Some(event));
+}
+
+#[test]
+fn test_read_non_utf8_header_value() {
+    let headers = OwnedHeaders::new()
+        .insert(Header { key: "ce_specversion", value: Some("1.0") })
+        .insert(Header { key: "ce_id", value: Some(&[0xff, 0xfe][..]) });
+    let message = OwnedMessage::new(None, None, "events".to_string(), Timestamp::NotAvailable, 0, 0, Some(headers));
+
+    match read_event(&message) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "id"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
+
+#[test]
+fn test_read_binary_without_content_type() {
+    let headers = OwnedHeaders::new()
+        .insert(Header { key: "ce_specversion", value: Some("1.0") })
+        .insert(Header { key: "ce_id", value: Some("A234-1234-1234") })
+        .insert(Header { key: "ce_source", value: Some("https://github.com/cloudevents/spec/pull") })
+        .insert(Header { key: "ce_type", value: Some("com.github.pull.create") });
+    let message = OwnedMessage::new(
+        Some(b"{}".to_vec()),
+        None,
+        "events".to_string(),
+        Timestamp::NotAvailable,
+        0,
+        0,
+        Some(headers),
+    );
+
+    match read_event(&message) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "datacontenttype"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
+
+#[test]
+fn test_read_ignores_binary_non_ce_headers() {
+    let mut record = MessageRecord::from_binary_event(event()).unwrap();
+    record.headers = record.headers.insert(Header { key: "traceparent-bin", value: Some(&[0xff, 0x00][..]) });
+
+    assert_eq!(read_event(&message(record)).unwrap(), Some(event()));
+}
+
+#[test]
+fn test_read_record_without_event() {
+    let headers = OwnedHeaders::new().insert(Header { key: "content-type", value: Some("text/plain") });
+    let message = OwnedMessage::new(
+        Some(b"hello".to_vec()),
+        Some(b"key-1".to_vec()),
+        "events".to_string(),
+        Timestamp::NotAvailable,
+        0,
+        0,
+        Some(headers),
+    );
+
+    assert_eq!(read_event(&message).unwrap(), None);
+}
+
+#[test]
+fn test_read_partition_key_from_record_key() {
+    let mut without_key = event();
+    without_key.extensions.remove("partitionkey");
+    let mut binary = MessageRecord::from_binary_event(without_key.clone()).unwrap();
+    binary.key = Some(b"key-2".to_vec());
+    // The attribute carried by the event takes precedence over the record key
+    let mut structured = MessageRecord::from_structured_event(event()).unwrap();
+    structured.key = Some(b"key-2".to_vec());
+
+    let from_binary = read_event(&message(binary)).unwrap().unwrap();
+    let from_structured = read_event(&message(structured)).unwrap().unwrap();
+
+    assert_eq!(from_binary.extensions.get("partitionkey"), Some(&ExtensionValue::from("key-2")));
+    assert_eq!(from_structured.extensions.get("partitionkey"), Some(&ExtensionValue::from("key-1")));
+    let unkeyed = message(MessageRecord::from_binary_event(without_key.clone()).unwrap());
+    assert_eq!(read_event(&unkeyed).unwrap(), Some(without_key));
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "mqtt")]
+
+mod common;
+
+use bytes::BytesMut;
+use cloudevents::http::ReaderError;
+use cloudevents::mqtt::{v3, v5};
+use cloudevents::ExtensionValue;
+use common::full_event;
+use rumqttc::v5::mqttbytes::v5::{Packet, Publish, PublishProperties};
+use rumqttc::v5::mqttbytes::QoS;
+
+// Encodes and decodes the packet, as it would be sent over the wire, with the packet id assigned by the client
+fn transfer_v5(mut publish: Publish) -> Publish {
+    publish.pkid = 1;
+    let mut buffer = BytesMut::new();
+    publish.write(&mut buffer).unwrap();
+    match Packet::read(&mut buffer, None).unwrap() {
+        Packet::Publish(p) => p,
+        p => panic!("Expecting publish, found {:?}", p),
+    }
+}
+
+fn transfer_v3(mut publish: rumqttc::Publish) -> rumqttc::Publish {
+    publish.pkid = 1;
+    let mut buffer = BytesMut::new();
+    publish.write(&mut buffer).unwrap();
+    match rumqttc::mqttbytes::v4::read(&mut buffer, 1024).unwrap() {
+        rumqttc::Packet::Publish(p) => p,
+        p => panic!("Expecting publish, found {:?}", p),
+    }
+}
+
+#[test]
+fn test_v5_binary_round_trip() {
+    let publish = transfer_v5(v5::write_binary(full_event(), "events", QoS::AtLeastOnce).unwrap());
+    let properties = publish.properties.as_ref().unwrap();
+
+    assert_eq!(properties.content_type.as_deref(), Some("application/xml"));
+    assert!(properties
+        .user_properties
+        .contains(&("comexampleextension1".to_string(), "value".to_string())));
+    assert_eq!(publish.payload.as_ref(), "<much wow=\"xml\"/>".as_bytes());
+    assert_eq!(v5::read_event(&publish).unwrap(), Some(full_event()));
+}
+
+#[test]
+fn test_v5_structured_round_trip() {
+    let publish = transfer_v5(v5::write_structured(full_event(), "events", QoS::AtLeastOnce).unwrap());
+    let properties = publish.properties.as_ref().unwrap();
+
+    assert_eq!(properties.content_type.as_deref(), Some("application/cloudevents+json"));
+    assert!(properties.user_properties.is_empty());
+    assert_eq!(v5::read_event(&publish).unwrap(), Some(full_event()));
+}
+
+#[test]
+fn test_v5_user_properties_are_raw_utf8() {
+    let mut event = full_event();
+    event
+        .extensions
+        .insert("comexampleextension2".to_string(), ExtensionValue::from("välue with spaces"));
+
+    let publish = transfer_v5(v5::write_binary(event.clone(), "events", QoS::AtMostOnce).unwrap());
+
+    // User properties are UTF-8 strings, so values aren't percent-encoded like HTTP headers
+    assert!(publish
+        .properties
+        .as_ref()
+        .unwrap()
+        .user_properties
+        .contains(&("comexampleextension2".to_string(), "välue with spaces".to_string())));
+    assert_eq!(v5::read_event(&publish).unwrap(), Some(event));
+}
+
+#[test]
+fn test_v5_read_binary_without_content_type() {
+    let properties = PublishProperties {
+        user_properties: vec![
+            ("specversion".to_string(), "1.0".to_string()),
+            ("id".to_string(), "1".to_string()),
+            ("source".to_string(), "/source".to_string()),
+            ("type".to_string(), "t".to_string()),
+        ],
+        ..Default::default()
+    };
+    let publish = transfer_v5(Publish::new("events", QoS::AtMostOnce, "{\"hello\":\"world\"}", Some(properties)));
+
+    match v5::read_event(&publish) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "datacontenttype"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
+
+#[test]
+fn test_v5_read_packet_without_event() {
+    let properties = PublishProperties {
+        user_properties: vec![("id".to_string(), "1".to_string())],
+        content_type: Some("text/plain".to_string()),
+        ..Default::default()
+    };
+    let with_properties = Publish::new("events", QoS::AtMostOnce, "hello", Some(properties));
+    let plain = Publish::new("events", QoS::AtMostOnce, "hello", None);
+
+    assert_eq!(v5::read_event(&with_properties).unwrap(), None);
+    assert_eq!(v5::read_event(&plain).unwrap(), None);
+}
+
+#[test]
+fn test_v3_structured_round_trip() {
+    let publish = transfer_v3(v3::write_structured(full_event(), "events", rumqttc::QoS::AtLeastOnce).unwrap());
+
+    assert_eq!(v3::read_event(&publish).unwrap(), full_event());
+}
+
+#[test]
+fn test_v3_is_structured_only() {
+    let publish = transfer_v3(v3::write_structured(full_event(), "events", rumqttc::QoS::AtMostOnce).unwrap());
+    let json: serde_json::Value = serde_json::from_slice(&publish.payload).unwrap();
+
+    // Without properties, all attributes and the data travel in the JSON payload
+    assert_eq!(json["id"], "A234-1234-1234");
+    assert_eq!(json["comexampleextension1"], "value");
+    assert_eq!(json["data"], "<much wow=\"xml\"/>");
+
+    // The data of a binary mode event can't be read as an event
+    let binary = rumqttc::Publish::new("events", rumqttc::QoS::AtMostOnce, "<much wow=\"xml\"/>");
+    match v3::read_event(&binary) {
+        Err(ReaderError::Other(_)) => (),
+        r => panic!("Expecting JSON error, found {:?}", r),
+    }
+}
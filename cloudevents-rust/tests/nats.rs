@@ -0,0 +1,119 @@
+#![cfg(feature = "nats")]
+
+mod common;
+
+use async_nats::{HeaderMap, Message, Subject};
+use cloudevents::http::ReaderError;
+use cloudevents::nats::{read_event, MessageRecord};
+use cloudevents::ExtensionValue;
+use common::full_event;
+
+fn message(headers: Option<HeaderMap>, payload: Vec<u8>) -> Message {
+    Message {
+        subject: Subject::from("events"),
+        reply: None,
+        length: payload.len(),
+        payload: payload.into(),
+        headers,
+        status: None,
+        description: None,
+    }
+}
+
+fn received(record: MessageRecord) -> Message {
+    message(record.headers, record.payload)
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let message = received(MessageRecord::from_binary_event(full_event()).unwrap());
+    let headers = message.headers.as_ref().unwrap();
+
+    assert_eq!(headers.get("ce-id").unwrap().as_str(), "A234-1234-1234");
+    assert_eq!(headers.get("ce-comexampleextension1").unwrap().as_str(), "value");
+    assert_eq!(headers.get("content-type").unwrap().as_str(), "application/xml");
+    assert_eq!(read_event(&message).unwrap(), Some(full_event()));
+}
+
+#[test]
+fn test_structured_round_trip() {
+    let message = received(MessageRecord::from_structured_event(full_event()).unwrap());
+
+    assert!(message.headers.is_none());
+    assert_eq!(read_event(&message).unwrap(), Some(full_event()));
+}
+
+#[test]
+fn test_binary_header_values_are_percent_encoded() {
+    let mut event = full_event();
+    event
+        .extensions
+        .insert("comexampleextension2".to_string(), ExtensionValue::from("välue with spaces"));
+
+    let message = received(MessageRecord::from_binary_event(event.clone()).unwrap());
+
+    // NATS headers follow the HTTP header rules, so non-ASCII values are percent-encoded
+    assert_eq!(
+        message.headers.as_ref().unwrap().get("ce-comexampleextension2").unwrap().as_str(),
+        "v%C3%A4lue%20with%20spaces"
+    );
+    assert_eq!(read_event(&message).unwrap(), Some(event));
+}
+
+#[test]
+fn test_read_non_utf8_header_value() {
+    let mut headers = HeaderMap::new();
+    headers.insert("ce-specversion", "1.0");
+    headers.insert("ce-id", "%FF%FE");
+    let message = message(Some(headers), Vec::new());
+
+    match read_event(&message) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "id"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
+
+#[test]
+fn test_read_binary_without_content_type() {
+    let mut headers = HeaderMap::new();
+    headers.insert("ce-specversion", "1.0");
+    headers.insert("ce-id", "1");
+    headers.insert("ce-source", "/source");
+    headers.insert("ce-type", "t");
+    let message = message(Some(headers), b"{}".to_vec());
+
+    match read_event(&message) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "datacontenttype"),
+        r => panic!("Expecting invalid metadata error, found {:?}", r),
+    }
+}
+
+#[test]
+fn test_read_datacontentencoding() {
+    let message = |spec_version: &str| {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", "text/plain");
+        headers.insert("ce-specversion", spec_version);
+        headers.insert("ce-id", "1");
+        headers.insert("ce-source", "/source");
+        headers.insert("ce-type", "t");
+        headers.insert("ce-datacontentencoding", "base64");
+        message(Some(headers), b"aGVsbG8=".to_vec())
+    };
+
+    // Only spec 0.3 allows the data to be base64 encoded
+    let v03 = read_event(&message("0.3")).unwrap().unwrap();
+    assert_eq!(v03.payload.unwrap().data, b"hello");
+    assert!(v03.extensions.is_empty());
+
+    let v10 = read_event(&message("1.0")).unwrap().unwrap();
+    assert_eq!(v10.payload.unwrap().data, b"aGVsbG8=");
+    assert_eq!(v10.extensions.get("datacontentencoding"), Some(&ExtensionValue::from("base64")));
+}
+
+#[test]
+fn test_read_message_without_headers() {
+    // Without ce- headers, the payload is expected to be a structured mode event
+    assert_eq!(read_event(&message(None, Vec::new())).unwrap(), None);
+    assert!(read_event(&message(None, b"not json".to_vec())).is_err());
+}
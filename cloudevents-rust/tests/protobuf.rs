@@ -0,0 +1,136 @@
+#![cfg(feature = "protobuf")]
+
+use chrono::DateTime;
+use cloudevents::http::ReaderError;
+use cloudevents::protobuf::cloud_event::cloud_event_attribute_value::Attr;
+use cloudevents::protobuf::cloud_event::{CloudEventAttributeValue, Data};
+use cloudevents::protobuf::CloudEvent;
+use cloudevents::{Event, EventBuilder, ExtensionValue, Payload, SpecVersion};
+use prost::Message;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use url::Url;
+
+fn event(content_type: &str, data: &[u8]) -> Event {
+    let mut extensions = HashMap::new();
+    extensions.insert("comexampleextension1".to_string(), ExtensionValue::from("value"));
+    extensions.insert("comexampleextension2".to_string(), ExtensionValue::from(10));
+    extensions.insert("comexampleextension3".to_string(), ExtensionValue::from(true));
+    extensions.insert("comexampleextension4".to_string(), ExtensionValue::Binary(vec![1, 2, 3]));
+    extensions.insert(
+        "comexampleextension5".to_string(),
+        ExtensionValue::from(DateTime::parse_from_rfc3339("2020-01-01T10:00:00.5Z").unwrap()),
+    );
+
+    EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .spec_version(SpecVersion::V10)
+        .event_type("com.github.pull.create")
+        .subject("123")
+        .time(DateTime::parse_from_rfc3339("2018-04-05T17:31:00Z").unwrap())
+        .data_schema(Url::parse("https://example.com/schema").unwrap())
+        .extensions(extensions)
+        .payload(Payload {
+            content_type: content_type.to_string(),
+            data: data.to_vec(),
+        })
+        .build()
+        .unwrap()
+}
+
+fn attribute(message: &CloudEvent, name: &str) -> Option<Attr> {
+    message.attributes.get(name).and_then(|v| v.attr.clone())
+}
+
+// Encodes and decodes the message, as it would be sent over the wire
+fn transfer(message: CloudEvent) -> CloudEvent {
+    CloudEvent::decode(message.encode_to_vec().as_slice()).unwrap()
+}
+
+#[test]
+fn test_round_trip_with_typed_attributes() {
+    let message = transfer(CloudEvent::from(event("application/xml", b"<much wow=\"xml\"/>")));
+
+    assert_eq!(message.id, "A234-1234-1234");
+    assert_eq!(message.spec_version, "1.0");
+    assert_eq!(message.r#type, "com.github.pull.create");
+    assert_eq!(
+        attribute(&message, "dataschema"),
+        Some(Attr::CeUri("https://example.com/schema".to_string()))
+    );
+    assert_eq!(attribute(&message, "comexampleextension2"), Some(Attr::CeInteger(10)));
+    assert_eq!(attribute(&message, "comexampleextension3"), Some(Attr::CeBoolean(true)));
+    assert_eq!(attribute(&message, "comexampleextension4"), Some(Attr::CeBytes(vec![1, 2, 3])));
+    assert_eq!(
+        attribute(&message, "datacontenttype"),
+        Some(Attr::CeString("application/xml".to_string()))
+    );
+    assert_eq!(message.data, Some(Data::TextData("<much wow=\"xml\"/>".to_string())));
+
+    assert_eq!(
+        Event::try_from(message).unwrap(),
+        event("application/xml", b"<much wow=\"xml\"/>")
+    );
+}
+
+#[test]
+fn test_round_trip_binary_data() {
+    let message = transfer(CloudEvent::from(event("application/octet-stream", &[0, 159, 146, 150])));
+
+    assert_eq!(message.data, Some(Data::BinaryData(vec![0, 159, 146, 150])));
+    assert_eq!(
+        Event::try_from(message).unwrap(),
+        event("application/octet-stream", &[0, 159, 146, 150])
+    );
+}
+
+#[test]
+fn test_read_proto_data() {
+    let message = CloudEvent {
+        id: "A234-1234-1234".to_string(),
+        source: "https://github.com/cloudevents/spec/pull".to_string(),
+        spec_version: "1.0".to_string(),
+        r#type: "com.github.pull.create".to_string(),
+        attributes: HashMap::new(),
+        data: Some(Data::ProtoData(prost_types::Any {
+            type_url: "type.googleapis.com/google.protobuf.Duration".to_string(),
+            value: vec![8, 1],
+        })),
+    };
+
+    let event = Event::try_from(transfer(message)).unwrap();
+
+    assert_eq!(
+        event.payload,
+        Some(Payload {
+            content_type: "application/protobuf".to_string(),
+            data: vec![8, 1],
+        })
+    );
+    assert_eq!(
+        event.data_schema,
+        Some(Url::parse("https://type.googleapis.com/google.protobuf.Duration").unwrap())
+    );
+
+    match CloudEvent::from(event).data {
+        Some(Data::ProtoData(any)) => {
+            assert_eq!(any.type_url, "https://type.googleapis.com/google.protobuf.Duration");
+            assert_eq!(any.value, vec![8, 1]);
+        }
+        _ => panic!("Expecting proto data"),
+    }
+}
+
+#[test]
+fn test_read_missing_attribute_value() {
+    let mut message = CloudEvent::from(event("text/plain", b"hello"));
+    message
+        .attributes
+        .insert("comexampleextension1".to_string(), CloudEventAttributeValue { attr: None });
+
+    match Event::try_from(message) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "comexampleextension1"),
+        _ => panic!("Expecting invalid metadata error"),
+    }
+}
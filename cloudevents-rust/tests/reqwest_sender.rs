@@ -0,0 +1,99 @@
+#![cfg(feature = "reqwest")]
+
+mod common;
+
+use cloudevents::http::{HttpEvent, ReqwestSender, SenderError};
+use common::event;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+type Handler = fn(Vec<(String, String)>, Vec<u8>) -> (u16, Vec<(String, String)>, Vec<u8>);
+
+// Minimal HTTP/1.1 server answering a single request, returning its url
+fn serve_once(handler: Handler) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        let mut headers = Vec::new();
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        loop {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+            let l = line.trim_end();
+            if l.is_empty() {
+                break;
+            }
+            let (name, value) = l.split_once(':').unwrap();
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+        let length = headers
+            .iter()
+            .find(|(n, _)| n == "content-length")
+            .map(|(_, v)| v.parse().unwrap())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+
+        let (status, headers, body) = handler(headers, body);
+        let mut response = format!("HTTP/1.1 {} Status\r\ncontent-length: {}\r\n", status, body.len());
+        for (name, value) in headers {
+            response.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        response.push_str("\r\n");
+        let mut stream = stream;
+        stream.write_all(response.as_bytes()).unwrap();
+        stream.write_all(&body).unwrap();
+    });
+
+    format!("http://{}/", addr)
+}
+
+#[tokio::test]
+async fn test_send_and_read_reply() {
+    let url = serve_once(|headers, body| {
+        let echoed = headers
+            .into_iter()
+            .filter(|(n, _)| n.starts_with("ce-") || n == "content-type")
+            .collect();
+        (200, echoed, body)
+    });
+
+    let reply = ReqwestSender::new(reqwest::Client::new(), url)
+        .send(HttpEvent::Binary(event()))
+        .await
+        .unwrap();
+
+    assert_eq!(reply, Some(HttpEvent::Binary(event())));
+}
+
+#[tokio::test]
+async fn test_send_no_reply() {
+    let url = serve_once(|_, _| (202, Vec::new(), Vec::new()));
+
+    let reply = ReqwestSender::new(reqwest::Client::new(), url)
+        .send(HttpEvent::Structured(event()))
+        .await
+        .unwrap();
+
+    assert_eq!(reply, None);
+}
+
+#[tokio::test]
+async fn test_send_invalid_status() {
+    let url = serve_once(|_, _| (500, Vec::new(), Vec::new()));
+
+    let result = ReqwestSender::new(reqwest::Client::new(), url)
+        .send(HttpEvent::Binary(event()))
+        .await;
+
+    match result {
+        Err(SenderError::InvalidStatus { status }) => assert_eq!(status, 500),
+        _ => panic!("Expecting invalid status error"),
+    }
+}
@@ -1,6 +1,7 @@
-use cloudevents::{SpecVersion, Event, Payload};
+use cloudevents::{SpecVersion, Event, ExtensionValue, Payload, PayloadReader};
 use chrono::DateTime;
 use serde_json::json;
+use url::Url;
 
 #[test]
 fn test_serialize_no_payload_no_extensions() {
@@ -62,7 +63,7 @@ fn test_serialize_no_payload_with_extensions() {
     assert_eq!(v.time, Some(expected_time));
     assert_eq!(v.payload, None);
     assert!(!v.extensions.is_empty());
-    assert_eq!(v.extensions.get("stuff"), Some(&expected_stuff.to_string()));
+    assert_eq!(v.extensions.get("stuff"), Some(&ExtensionValue::from(expected_stuff)));
 }
 
 #[test]
@@ -86,7 +87,7 @@ fn test_serialize_with_payload_with_extensions() {
             "time" : expected_time.to_rfc3339(),
             "stuff": expected_stuff,
             "datacontenttype": expected_content_type,
-            "data": expected_data
+            "data": {"hello": "world"}
         });
 
     let v: Event = serde_json::from_value(j).unwrap();
@@ -105,5 +106,313 @@ fn test_serialize_with_payload_with_extensions() {
         })
     );
     assert!(!v.extensions.is_empty());
-    assert_eq!(v.extensions.get("stuff"), Some(&expected_stuff.to_string()));
+    assert_eq!(v.extensions.get("stuff"), Some(&ExtensionValue::from(expected_stuff)));
+}
+
+#[test]
+fn test_deserialize_with_data_base64() {
+    let expected_content_type = "application/octet-stream";
+    let expected_data: Vec<u8> = vec![0x00, 0x9f, 0x92, 0x96, 0xff];
+
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "datacontenttype": expected_content_type,
+            "data_base64": "AJ+Slv8="
+        });
+
+    let v: Event = serde_json::from_value(j).unwrap();
+
+    assert_eq!(
+        v.payload,
+        Some(Payload {
+            content_type: expected_content_type.to_string(),
+            data: expected_data
+        })
+    );
+    assert!(v.extensions.is_empty());
+}
+
+#[test]
+fn test_serialize_binary_payload_round_trip() {
+    let mut event = Event::new();
+    event.payload = Some(Payload {
+        content_type: "image/png".to_string(),
+        data: vec![0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a],
+    });
+
+    let j = serde_json::to_value(&event).unwrap();
+
+    assert_eq!(j["data_base64"], json!("iVBORw0KGgo="));
+    assert!(j.get("data").is_none());
+
+    let v: Event = serde_json::from_value(j).unwrap();
+
+    assert_eq!(v, event);
+}
+
+#[test]
+fn test_serialize_text_payload_round_trip() {
+    let mut event = Event::new();
+    event.payload = Some(Payload {
+        content_type: "text/plain".to_string(),
+        data: "hello world".as_bytes().into(),
+    });
+
+    let j = serde_json::to_value(&event).unwrap();
+
+    assert_eq!(j["data"], json!("hello world"));
+    assert!(j.get("data_base64").is_none());
+
+    let v: Event = serde_json::from_value(j).unwrap();
+
+    assert_eq!(v, event);
+}
+
+#[test]
+fn test_deserialize_with_json_data() {
+    let expected_content_type = "application/json";
+
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "datacontenttype": expected_content_type,
+            "data": {"hello": "world"}
+        });
+
+    let v: Event = serde_json::from_value(j).unwrap();
+
+    assert_eq!(
+        v.payload,
+        Some(Payload {
+            content_type: expected_content_type.to_string(),
+            data: r#"{"hello":"world"}"#.as_bytes().into()
+        })
+    );
+}
+
+#[test]
+fn test_serialize_json_payload_round_trip() {
+    for (content_type, data) in [
+        ("application/json", json!({"hello": "world"})),
+        ("application/json; charset=utf-8", json!([1, 2, 3])),
+        ("application/vnd.custom+json", json!(42)),
+        ("application/json", json!(true)),
+        ("application/json", json!("hello")),
+    ] {
+        let mut event = Event::new();
+        event.payload = Some(Payload {
+            content_type: content_type.to_string(),
+            data: serde_json::to_vec(&data).unwrap(),
+        });
+
+        let j = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(j["data"], data);
+
+        let v: Event = serde_json::from_value(j).unwrap();
+
+        assert_eq!(v, event);
+    }
+}
+
+#[test]
+fn test_deserialize_json_string_data() {
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "datacontenttype": "application/json",
+            "data": "hello"
+        });
+
+    let v: Event = serde_json::from_value(j.clone()).unwrap();
+
+    assert_eq!(v.payload.as_ref().unwrap().data, r#""hello""#.as_bytes());
+    assert_eq!(v.read_payload().unwrap().unwrap(), json!("hello"));
+    assert_eq!(serde_json::to_value(&v).unwrap(), j);
+}
+
+#[test]
+fn test_deserialize_null_attributes_as_unset() {
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "subject": null,
+            "myext": null,
+            "datacontenttype": null,
+            "data": null
+        });
+
+    let v: Event = serde_json::from_value(j).unwrap();
+
+    assert_eq!(v.subject, None);
+    assert_eq!(v.payload, None);
+    assert!(v.extensions.is_empty());
+}
+
+#[test]
+fn test_deserialize_data_base64_without_datacontenttype() {
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "data_base64": "iVBORw0KGgo="
+        });
+
+    let v: Event = serde_json::from_value(j).unwrap();
+
+    assert_eq!(v.payload.unwrap().content_type, "application/octet-stream");
+}
+
+#[test]
+fn test_payload_round_trip() {
+    let payload = Payload {
+        content_type: "image/png".to_string(),
+        data: vec![137, 80, 78, 71, 13, 10, 26, 10],
+    };
+
+    let j = serde_json::to_value(&payload).unwrap();
+
+    assert_eq!(j, json!({"datacontenttype": "image/png", "data_base64": "iVBORw0KGgo="}));
+    assert_eq!(serde_json::from_value::<Payload>(j).unwrap(), payload);
+}
+
+#[test]
+fn test_serialize_with_dataschema() {
+    let expected_data_schema = "https://example.com/schemas/pull.json";
+
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "dataschema": expected_data_schema
+        });
+
+    let v: Event = serde_json::from_value(j.clone()).unwrap();
+
+    assert_eq!(v.data_schema, Some(Url::parse(expected_data_schema).unwrap()));
+    assert!(v.extensions.is_empty());
+    assert_eq!(serde_json::to_value(&v).unwrap(), j);
+}
+
+#[test]
+fn test_deserialize_invalid_dataschema() {
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "dataschema": "not a uri"
+        });
+
+    assert!(serde_json::from_value::<Event>(j).is_err());
+}
+
+#[test]
+fn test_serialize_typed_extensions_round_trip() {
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "sequence": 5,
+            "sampled": true,
+            "traceparent": "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+        });
+
+    let v: Event = serde_json::from_value(j.clone()).unwrap();
+
+    assert_eq!(v.extensions.get("sequence"), Some(&ExtensionValue::Integer(5)));
+    assert_eq!(v.extensions.get("sampled"), Some(&ExtensionValue::Boolean(true)));
+    assert_eq!(
+        v.extensions.get("traceparent").and_then(|e| e.as_str()),
+        Some("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01")
+    );
+    assert_eq!(serde_json::to_value(&v).unwrap(), j);
+}
+
+#[test]
+fn test_extension_value_canonical_string() {
+    let time = DateTime::parse_from_rfc3339("2018-04-05T17:31:00Z").unwrap();
+
+    assert_eq!(ExtensionValue::from(true).to_string(), "true");
+    assert_eq!(ExtensionValue::from(-12).to_string(), "-12");
+    assert_eq!(ExtensionValue::from(vec![0x00, 0xff]).to_string(), "AP8=");
+    assert_eq!(ExtensionValue::from(time).to_string(), time.to_rfc3339());
+
+    assert_eq!(ExtensionValue::from("false").as_bool(), Some(false));
+    assert_eq!(ExtensionValue::from("-12").as_integer(), Some(-12));
+    assert_eq!(ExtensionValue::from("AP8=").as_binary(), Some(vec![0x00, 0xff]));
+    assert_eq!(ExtensionValue::from(time.to_rfc3339()).as_timestamp(), Some(time));
+}
+
+#[test]
+fn test_deserialize_v03() {
+    let expected_schema_url = "https://example.com/schemas/pull.json";
+
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "0.3",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "schemaurl": expected_schema_url,
+            "datacontenttype": "application/octet-stream",
+            "datacontentencoding": "base64",
+            "data": "AJ+Slv8="
+        });
+
+    let v: Event = serde_json::from_value(j.clone()).unwrap();
+
+    assert_eq!(v.spec_version, SpecVersion::V03);
+    assert_eq!(v.data_schema, Some(Url::parse(expected_schema_url).unwrap()));
+    assert_eq!(
+        v.payload,
+        Some(Payload {
+            content_type: "application/octet-stream".to_string(),
+            data: vec![0x00, 0x9f, 0x92, 0x96, 0xff]
+        })
+    );
+    assert!(v.extensions.is_empty());
+    assert_eq!(serde_json::to_value(&v).unwrap(), j);
+}
+
+#[test]
+fn test_convert_v03_to_v10_and_back() {
+    let j = json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "0.3",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "schemaurl": "https://example.com/schemas/pull.json",
+            "datacontenttype": "application/octet-stream",
+            "datacontentencoding": "base64",
+            "data": "AJ+Slv8="
+        });
+
+    let v03: Event = serde_json::from_value(j.clone()).unwrap();
+    let v10 = v03.clone().into_spec_version(SpecVersion::V10);
+
+    assert_eq!(
+        serde_json::to_value(&v10).unwrap(),
+        json!({
+            "id" : "A234-1234-1234",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull",
+            "dataschema": "https://example.com/schemas/pull.json",
+            "datacontenttype": "application/octet-stream",
+            "data_base64": "AJ+Slv8="
+        })
+    );
+    assert_eq!(v10.into_spec_version(SpecVersion::V03), v03);
 }
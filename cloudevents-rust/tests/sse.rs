@@ -0,0 +1,91 @@
+use cloudevents::sse::{write_event, SseParser};
+use cloudevents::{Event, EventBuilder, Payload};
+
+fn event(id: &str) -> Event {
+    EventBuilder::default()
+        .id(id)
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello\nworld".as_bytes().into(),
+        })
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_write_event() {
+    let message = write_event(&event("1")).unwrap();
+
+    assert!(message.starts_with("id: 1\nevent: com.github.pull.create\ndata: {"));
+    assert!(message.ends_with("}\n\n"));
+    assert_eq!(message.matches('\n').count(), 4);
+}
+
+#[test]
+fn test_parse_written_events_in_chunks() {
+    let stream = [write_event(&event("1")).unwrap(), write_event(&event("2")).unwrap()].concat();
+    let mut parser = SseParser::new();
+
+    let events: Vec<Event> = stream
+        .as_bytes()
+        .chunks(7)
+        .flat_map(|chunk| parser.feed(chunk))
+        .map(Result::unwrap)
+        .collect();
+
+    assert_eq!(events, vec![event("1"), event("2")]);
+}
+
+#[test]
+fn test_parse_comments_crlf_and_multiline_data() {
+    let stream = concat!(
+        ": keep-alive\r\n",
+        "\r\n",
+        "id: 1\r\n",
+        "retry: 1000\r\n",
+        "data: {\"id\":\"1\",\"specversion\":\"1.0\",\r\n",
+        "data:\"type\":\"t\",\"source\":\"/s\"}\r\n",
+        "\r\n",
+    );
+
+    let events = SseParser::new().feed(stream.as_bytes());
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_ref().unwrap().id, "1");
+}
+
+#[test]
+fn test_parse_cr_line_terminators() {
+    let stream = concat!(
+        "id: 1\r",
+        "data: {\"id\":\"1\",\"specversion\":\"1.0\",\"type\":\"t\",\"source\":\"/s\"}\r",
+        "\r",
+        "data: {\"id\":\"2\",\"specversion\":\"1.0\",\"type\":\"t\",\"source\":\"/s\"}\r\n",
+        "\r\n",
+    );
+    let mut parser = SseParser::new();
+
+    // Single bytes split the \r\n terminators across chunks
+    let ids: Vec<String> = stream
+        .as_bytes()
+        .chunks(1)
+        .flat_map(|chunk| parser.feed(chunk))
+        .map(|e| e.unwrap().id)
+        .collect();
+
+    assert_eq!(ids, vec!["1", "2"]);
+    assert!(parser.feed(b"\n").is_empty());
+}
+
+#[test]
+fn test_parse_invalid_event() {
+    let mut parser = SseParser::new();
+
+    let events = parser.feed(b"data: {}\n\ndata: not json\n");
+
+    assert_eq!(events.len(), 1);
+    assert!(events[0].is_err());
+    assert!(parser.feed(b"\n")[0].is_err());
+}
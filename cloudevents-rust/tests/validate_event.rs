@@ -0,0 +1,74 @@
+use cloudevents::http::{EventReader, HttpEvent, ReaderError};
+use cloudevents::{Event, EventBuilder, ExtensionValue, StrictEvent, ValidationError};
+use serde_json::json;
+
+struct BinaryReader {}
+
+impl EventReader<Event> for BinaryReader {
+    fn read_cloud_event(req: Event) -> Result<Option<HttpEvent>, ReaderError> {
+        Ok(Some(HttpEvent::Binary(req)))
+    }
+}
+
+#[test]
+fn test_validate_valid_event() {
+    let mut event = EventBuilder::default()
+        .source("/cloudevents/spec/pull/123")
+        .build()
+        .unwrap();
+    event.extensions.insert("sequence".to_string(), ExtensionValue::from(5));
+
+    assert_eq!(event.validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_reports_all_violations() {
+    let mut event = EventBuilder::default()
+        .id("")
+        .source("not a uri reference")
+        .build()
+        .unwrap();
+    event.extensions.insert("Trace-Parent".to_string(), ExtensionValue::from("abc"));
+    event.extensions.insert("id".to_string(), ExtensionValue::from("abc"));
+
+    let errors = event.validate().unwrap_err();
+
+    assert_eq!(
+        errors.iter().map(|e| e.attribute.as_str()).collect::<Vec<&str>>(),
+        vec!["id", "source", "Trace-Parent", "id"]
+    );
+}
+
+#[test]
+fn test_deserialize_strict_rejects_invalid_event() {
+    let j = json!({
+            "id" : "",
+            "specversion" : "1.0",
+            "type" : "com.github.pull.create",
+            "source" : "https://github.com/cloudevents/spec/pull"
+        });
+
+    assert!(serde_json::from_value::<Event>(j.clone()).is_ok());
+    assert!(serde_json::from_value::<StrictEvent>(j).is_err());
+}
+
+#[test]
+fn test_read_strict_rejects_invalid_event() {
+    let event = EventBuilder::default().source("").build().unwrap();
+
+    assert!(BinaryReader::read_cloud_event(event.clone()).is_ok());
+    match BinaryReader::read_cloud_event_strict(event) {
+        Err(ReaderError::InvalidMetadata { name, .. }) => assert_eq!(name, "source"),
+        _ => panic!("Expecting invalid metadata error"),
+    }
+}
+
+#[test]
+fn test_validation_error_display() {
+    let error = ValidationError {
+        attribute: "id".to_string(),
+        reason: "must be a non-empty string".to_string(),
+    };
+
+    assert_eq!(error.to_string(), "Invalid attribute 'id': must be a non-empty string");
+}
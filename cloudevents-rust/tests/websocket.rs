@@ -0,0 +1,45 @@
+mod common;
+
+use cloudevents::websocket::{read_event, write_event, Frame, Subprotocol};
+use common::event;
+
+#[test]
+fn test_negotiate_subprotocol() {
+    assert_eq!(Subprotocol::negotiate("cloudevents.json"), Some(Subprotocol::Json));
+    assert_eq!(Subprotocol::negotiate("cloudevents.avro, cloudevents.json"), Some(Subprotocol::Json));
+    assert_eq!(Subprotocol::negotiate("cloudevents.avro"), None);
+    assert_eq!(Subprotocol::negotiate(""), None);
+}
+
+#[test]
+fn test_json_frame_round_trip() {
+    let frame = write_event(&event(), Subprotocol::Json).unwrap();
+
+    match &frame {
+        Frame::Text(text) => assert!(text.contains(r#""id":"A234-1234-1234""#)),
+        f => panic!("Expecting text frame, found {:?}", f),
+    }
+    assert_eq!(read_event(&frame, Subprotocol::Json).unwrap(), event());
+}
+
+#[test]
+fn test_read_json_binary_frame() {
+    let frame = match write_event(&event(), Subprotocol::Json).unwrap() {
+        Frame::Text(text) => Frame::Binary(text.into_bytes()),
+        f => panic!("Expecting text frame, found {:?}", f),
+    };
+
+    assert_eq!(read_event(&frame, Subprotocol::Json).unwrap(), event());
+    assert!(read_event(&Frame::Text("{}".to_string()), Subprotocol::Json).is_err());
+}
+
+#[cfg(feature = "protobuf")]
+#[test]
+fn test_protobuf_frame_round_trip() {
+    let frame = write_event(&event(), Subprotocol::Protobuf).unwrap();
+
+    assert_eq!(Subprotocol::negotiate("cloudevents.proto, cloudevents.json"), Some(Subprotocol::Protobuf));
+    assert_eq!(read_event(&frame, Subprotocol::Protobuf).unwrap(), event());
+    assert!(matches!(frame, Frame::Binary(_)));
+    assert!(read_event(&Frame::Text("{}".to_string()), Subprotocol::Protobuf).is_err());
+}
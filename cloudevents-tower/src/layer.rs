@@ -0,0 +1,109 @@
+use bytes::Bytes;
+use cloudevents::http::{self, EventWriter as _};
+use cloudevents::Event;
+use cloudevents_hyper::{EventReader, EventWriter, LengthLimitError};
+use ::http::{Request, Response, StatusCode};
+use http_body::Body;
+use http_body_util::Full;
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Layer decoding requests into [`Event`]s for the inner service, and encoding the events it returns into responses.
+///
+/// The inner service is a `Service<Event, Response = Option<Event>>`:
+/// returned events are written in binary mode with 200 OK, while `None` is responded with 202 Accepted.
+/// Requests without an event, with a batch or failing to be read are responded with 400 Bad Request,
+/// and requests with a body over the limit with 413 Payload Too Large, without calling the inner service.
+#[derive(Clone, Default)]
+pub struct CloudEventLayer {
+    reader: EventReader,
+}
+
+impl CloudEventLayer {
+    pub fn new() -> Self {
+        CloudEventLayer::default()
+    }
+
+    /// See [`cloudevents_hyper::EventReader::limit`]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.reader = self.reader.limit(limit);
+        self
+    }
+
+    /// See [`cloudevents_hyper::EventReader::strict`]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.reader = self.reader.strict(strict);
+        self
+    }
+}
+
+impl<S> Layer<S> for CloudEventLayer {
+    type Service = CloudEventService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CloudEventService {
+            inner,
+            reader: self.reader.clone(),
+        }
+    }
+}
+
+/// Service created by [`CloudEventLayer`]
+#[derive(Clone)]
+pub struct CloudEventService<S> {
+    inner: S,
+    reader: EventReader,
+}
+
+impl<S, B> Service<Request<B>> for CloudEventService<S>
+where
+    S: Service<Event, Response = Option<Event>> + Clone + Send + 'static,
+    S::Future: Send,
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a clone in its place
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let reader = self.reader.clone();
+
+        Box::pin(async move {
+            let event = match reader.read_request(req).await {
+                Ok(Some(http::HttpEvent::Binary(e))) | Ok(Some(http::HttpEvent::Structured(e))) => e,
+                Ok(Some(http::HttpEvent::Batch(_))) => {
+                    return Ok(status(StatusCode::BAD_REQUEST, "Expecting a single event, found a batch"))
+                }
+                Ok(None) => return Ok(status(StatusCode::BAD_REQUEST, "No CloudEvent found in the request")),
+                Err(http::ReaderError::Other(e)) if e.is::<LengthLimitError>() => {
+                    return Ok(status(StatusCode::PAYLOAD_TOO_LARGE, &e.to_string()))
+                }
+                Err(e) => return Ok(status(StatusCode::BAD_REQUEST, &e.to_string())),
+            };
+
+            Ok(match inner.call(event).await? {
+                Some(e) => EventWriter::write_cloud_event(http::HttpEvent::Binary(e))
+                    .unwrap_or_else(|e| status(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string())),
+                None => status(StatusCode::ACCEPTED, ""),
+            })
+        })
+    }
+}
+
+fn status(status: StatusCode, body: &str) -> Response<Full<Bytes>> {
+    let mut res = Response::new(Full::from(body.to_string()));
+    *res.status_mut() = status;
+    res
+}
@@ -0,0 +1,3 @@
+mod layer;
+
+pub use layer::{CloudEventLayer, CloudEventService};
@@ -0,0 +1,109 @@
+use bytes::Bytes;
+use cloudevents::Event;
+use cloudevents_tower::CloudEventLayer;
+use http::{Request, StatusCode};
+use http_body_util::{BodyExt, Full};
+use std::convert::Infallible;
+use tower::{service_fn, ServiceBuilder, ServiceExt};
+
+async fn echo(event: Event) -> Result<Option<Event>, Infallible> {
+    Ok(Some(event))
+}
+
+async fn sink(_: Event) -> Result<Option<Event>, Infallible> {
+    Ok(None)
+}
+
+fn binary_request() -> http::request::Builder {
+    Request::post("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "1.0")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("content-type", "text/plain")
+}
+
+fn body(s: &'static str) -> Full<Bytes> {
+    Full::new(Bytes::from(s))
+}
+
+async fn body_text(body: Full<Bytes>) -> String {
+    String::from_utf8(body.collect().await.unwrap().to_bytes().to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_echo_event() {
+    let svc = ServiceBuilder::new().layer(CloudEventLayer::new()).service(service_fn(echo));
+
+    let res = svc
+        .oneshot(binary_request().body(body("hello")).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["ce-id"], "A234-1234-1234");
+    assert_eq!(res.headers()["content-type"], "text/plain");
+    assert_eq!(body_text(res.into_body()).await, "hello");
+}
+
+#[tokio::test]
+async fn test_accept_event_without_reply() {
+    let svc = ServiceBuilder::new().layer(CloudEventLayer::new()).service(service_fn(sink));
+
+    let req = Request::post("/")
+        .header("content-type", "application/cloudevents+json")
+        .body(body(r#"{"id":"1","specversion":"1.0","type":"t","source":"/s"}"#))
+        .unwrap();
+    let res = svc.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn test_reject_invalid_event_with_reader_error() {
+    let svc = ServiceBuilder::new()
+        .layer(CloudEventLayer::new())
+        .service(service_fn(|_: Event| async { panic!("Inner service should not be called") as Result<Option<Event>, Infallible> }));
+
+    let req = Request::post("/")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "9.9")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .body(body(""))
+        .unwrap();
+    let res = svc.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body_text(res.into_body()).await,
+        "Invalid metadata 'specversion': Invalid specversion 9.9"
+    );
+}
+
+#[tokio::test]
+async fn test_reject_batch() {
+    let svc = ServiceBuilder::new().layer(CloudEventLayer::new()).service(service_fn(echo));
+
+    let req = Request::post("/")
+        .header("content-type", "application/cloudevents-batch+json")
+        .body(body("[]"))
+        .unwrap();
+    let res = svc.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_reject_body_over_limit() {
+    let svc = ServiceBuilder::new()
+        .layer(CloudEventLayer::new().limit(2))
+        .service(service_fn(echo));
+
+    let res = svc
+        .oneshot(binary_request().body(body("hello")).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
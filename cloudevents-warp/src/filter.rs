@@ -0,0 +1,68 @@
+use cloudevents::http::{self, EventReader as _, HttpReader};
+use cloudevents::Event;
+use std::fmt;
+use warp::reject::{Reject, Rejection};
+use warp::Filter;
+
+/// Rejection of requests without an event or failing to be read.
+///
+/// Use [`warp::Filter::recover`] to turn it into a response, e.g. a 400 Bad Request with [`EventRejection::reason`].
+#[derive(Debug)]
+pub struct EventRejection {
+    reason: String,
+}
+
+impl EventRejection {
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for EventRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl Reject for EventRejection {}
+
+impl From<http::ReaderError> for EventRejection {
+    fn from(e: http::ReaderError) -> Self {
+        EventRejection { reason: e.to_string() }
+    }
+}
+
+/// Extracts the [`http::HttpEvent`] from the request, preserving the mode it was encoded with.
+///
+/// The whole body is buffered, combine it with [`warp::body::content_length_limit`] to limit its size.
+pub fn to_http_event() -> impl Filter<Extract = (http::HttpEvent,), Error = Rejection> + Clone {
+    to_http_event_with_format().map(|event, _| event)
+}
+
+/// Like [`to_http_event`], but also extracts the [`http::EventFormat`] the event was encoded with,
+/// to reply with [`from_http_event_with_format`](crate::reply::from_http_event_with_format)
+pub fn to_http_event_with_format(
+) -> impl Filter<Extract = (http::HttpEvent, http::EventFormat), Error = Rejection> + Clone {
+    warp::header::headers_cloned()
+        .and(warp::body::bytes())
+        .and_then(|headers, body| async move {
+            HttpReader::read_cloud_event_with_format((headers, body))
+                .map_err(|e| warp::reject::custom(EventRejection::from(e)))?
+                .ok_or_else(|| reject("No CloudEvent found in the request"))
+        })
+        .untuple_one()
+}
+
+/// Extracts a single event from the request, either in binary or structured mode. Batches are rejected.
+pub fn to_event() -> impl Filter<Extract = (Event,), Error = Rejection> + Clone {
+    to_http_event().and_then(|event| async move {
+        match event {
+            http::HttpEvent::Binary(e) | http::HttpEvent::Structured(e) => Ok(e),
+            http::HttpEvent::Batch(_) => Err(reject("Expecting a single event, found a batch")),
+        }
+    })
+}
+
+fn reject(reason: &str) -> Rejection {
+    warp::reject::custom(EventRejection { reason: reason.to_string() })
+}
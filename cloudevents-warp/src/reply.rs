@@ -0,0 +1,36 @@
+use cloudevents::http::{self, EventWriter as _, HttpWriter};
+use cloudevents::Event;
+use warp::http::StatusCode;
+use warp::reply::{Reply, Response};
+
+/// Reply encoding an event with the mode of the wrapped [`http::HttpEvent`] and the wrapped [`http::EventFormat`].
+///
+/// Events failing to be written are replied with 500 Internal Server Error.
+/// Use [`warp::reply::with_status`] to reply with a status code other than 200 OK.
+#[derive(PartialEq, Debug, Clone)]
+pub struct EventReply(pub http::HttpEvent, pub http::EventFormat);
+
+/// Replies with the event in binary mode
+pub fn from_event(event: Event) -> EventReply {
+    EventReply(http::HttpEvent::Binary(event), http::EventFormat::Json)
+}
+
+/// Replies with the event in its mode, using the JSON format in structured and batch mode
+pub fn from_http_event(event: http::HttpEvent) -> EventReply {
+    EventReply(event, http::EventFormat::Json)
+}
+
+/// Replies with the event in its mode and in the provided format,
+/// e.g. the one extracted by [`to_http_event_with_format`](crate::filter::to_http_event_with_format)
+pub fn from_http_event_with_format(event: http::HttpEvent, format: http::EventFormat) -> EventReply {
+    EventReply(event, format)
+}
+
+impl Reply for EventReply {
+    fn into_response(self) -> Response {
+        match HttpWriter::write_cloud_event_with_format(self.0, self.1) {
+            Ok(res) => res,
+            Err(e) => warp::reply::with_status(e.to_string(), StatusCode::INTERNAL_SERVER_ERROR).into_response(),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use cloudevents::http::HttpEvent;
+use cloudevents_warp::filter::{to_event, to_http_event, EventRejection};
+use warp::test::RequestBuilder;
+
+fn binary_request() -> RequestBuilder {
+    warp::test::request()
+        .method("POST")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "1.0")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("ce-comexampleextension1", "value")
+        .header("content-type", "application/json")
+        .body(r#"{"hello":"world"}"#)
+}
+
+fn batch_request() -> RequestBuilder {
+    warp::test::request()
+        .method("POST")
+        .header("content-type", "application/cloudevents-batch+json")
+        .body(r#"[{"id":"1","specversion":"1.0","type":"t","source":"/s"}]"#)
+}
+
+#[tokio::test]
+async fn test_extract_binary_event() {
+    let event = binary_request().filter(&to_event()).await.unwrap();
+
+    assert_eq!(event.id, "A234-1234-1234");
+    assert_eq!(event.extensions["comexampleextension1"], "value".into());
+    assert_eq!(event.payload.unwrap().data, r#"{"hello":"world"}"#.as_bytes());
+}
+
+#[tokio::test]
+async fn test_extract_structured_event() {
+    let event = warp::test::request()
+        .method("POST")
+        .header("content-type", "application/cloudevents+json")
+        .body(r#"{"id":"1","specversion":"1.0","type":"t","source":"/s"}"#)
+        .filter(&to_http_event())
+        .await
+        .unwrap();
+
+    match event {
+        HttpEvent::Structured(e) => assert_eq!(e.id, "1"),
+        e => panic!("Expecting structured event, found {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_extract_batch_http_event() {
+    let event = batch_request().filter(&to_http_event()).await.unwrap();
+
+    match event {
+        HttpEvent::Batch(events) => assert_eq!(events.len(), 1),
+        e => panic!("Expecting batch, found {:?}", e),
+    }
+}
+
+#[tokio::test]
+async fn test_reject_batch_for_single_event() {
+    let rejection = batch_request().filter(&to_event()).await.unwrap_err();
+
+    assert_eq!(
+        rejection.find::<EventRejection>().unwrap().reason(),
+        "Expecting a single event, found a batch"
+    );
+}
+
+#[tokio::test]
+async fn test_reject_invalid_event_with_reader_error() {
+    let rejection = binary_request()
+        .header("ce-specversion", "9.9")
+        .filter(&to_event())
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        rejection.find::<EventRejection>().unwrap().reason(),
+        "Invalid metadata 'specversion': Invalid specversion 9.9"
+    );
+}
+
+#[tokio::test]
+async fn test_reject_request_without_event() {
+    let rejection = warp::test::request().method("POST").filter(&to_event()).await.unwrap_err();
+
+    assert!(rejection.find::<EventRejection>().is_some());
+}
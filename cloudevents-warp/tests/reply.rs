@@ -0,0 +1,91 @@
+use cloudevents::http::HttpEvent;
+use cloudevents::{EventBuilder, Payload};
+use cloudevents_warp::filter::{to_http_event, to_http_event_with_format};
+use cloudevents_warp::reply::{from_event, from_http_event, from_http_event_with_format};
+use warp::http::StatusCode;
+use warp::Filter;
+
+#[tokio::test]
+async fn test_reply_binary_event() {
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello".as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+    let route = warp::any().map(move || from_event(event.clone()));
+
+    let res = warp::test::request().reply(&route).await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["ce-id"], "A234-1234-1234");
+    assert_eq!(res.headers()["content-type"], "text/plain");
+    assert_eq!(res.body().as_ref(), b"hello");
+}
+
+#[tokio::test]
+async fn test_reply_structured_event() {
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .build()
+        .unwrap();
+    let route = warp::any().map(move || from_http_event(HttpEvent::Structured(event.clone())));
+
+    let res = warp::test::request().reply(&route).await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "application/cloudevents+json");
+    assert!(!res.headers().contains_key("ce-id"));
+}
+
+#[tokio::test]
+async fn test_echo_round_trip() {
+    let route = to_http_event().map(from_http_event);
+
+    let res = warp::test::request()
+        .method("POST")
+        .header("ce-id", "A234-1234-1234")
+        .header("ce-specversion", "1.0")
+        .header("ce-type", "com.github.pull.create")
+        .header("ce-source", "https://github.com/cloudevents/spec/pull")
+        .header("content-type", "text/plain")
+        .body("hello")
+        .reply(&route)
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["ce-source"], "https://github.com/cloudevents/spec/pull");
+    assert_eq!(res.body().as_ref(), b"hello");
+}
+
+#[tokio::test]
+async fn test_echo_protobuf_round_trip() {
+    let route = to_http_event_with_format().map(from_http_event_with_format);
+    let event = EventBuilder::default()
+        .id("A234-1234-1234")
+        .source("https://github.com/cloudevents/spec/pull")
+        .event_type("com.github.pull.create")
+        .payload(Payload {
+            content_type: "text/plain".to_string(),
+            data: "hello".as_bytes().into(),
+        })
+        .build()
+        .unwrap();
+
+    let res = warp::test::request()
+        .method("POST")
+        .header("content-type", "application/cloudevents+protobuf")
+        .body(cloudevents::protobuf::to_vec(event.clone()))
+        .reply(&route)
+        .await;
+
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["content-type"], "application/cloudevents+protobuf");
+    assert_eq!(cloudevents::protobuf::from_slice(res.body()).unwrap(), event);
+}
@@ -0,0 +1,41 @@
+//! Regenerates the Protobuf messages and the gRPC service of `cloudevents-rust`
+//! from the files in `cloudevents-rust/proto`, with the vendored `protoc`:
+//!
+//! ```text
+//! cargo run --manifest-path codegen/Cargo.toml
+//! ```
+
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../cloudevents-rust");
+    let proto_dir = crate_dir.join("proto");
+    let out_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/generated");
+    fs::create_dir_all(&out_dir).unwrap();
+    env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path().unwrap());
+
+    tonic_prost_build::configure()
+        .build_client(false)
+        .build_server(false)
+        .out_dir(&out_dir)
+        .compile_protos(&[proto_dir.join("cloudevents.proto")], std::slice::from_ref(&proto_dir))
+        .unwrap();
+    copy(&out_dir, &crate_dir.join("src/protobuf/generated.rs"));
+
+    // The service refers to the messages generated above, and `build_transport(false)` skips the
+    // `connect` constructor, which relies on `TryInto` not being in the prelude of the 2018 edition
+    tonic_prost_build::configure()
+        .extern_path(".io.cloudevents.v1.CloudEvent", "crate::protobuf::CloudEvent")
+        .extern_path(".io.cloudevents.v1.CloudEventBatch", "crate::protobuf::CloudEventBatch")
+        .build_transport(false)
+        .out_dir(&out_dir)
+        .compile_protos(&[proto_dir.join("cloudevents_service.proto")], &[proto_dir])
+        .unwrap();
+    copy(&out_dir, &crate_dir.join("src/grpc/generated.rs"));
+}
+
+fn copy(out_dir: &Path, to: &Path) {
+    fs::copy(out_dir.join("io.cloudevents.v1.rs"), to).unwrap();
+    println!("Generated {}", to.display());
+}